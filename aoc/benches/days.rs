@@ -0,0 +1,69 @@
+use aoc_common::Solution;
+use bitvec::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Times parsing and both parts of `S` on `input`, as `dNN/<name>/...`.
+fn bench_day<S: Solution>(c: &mut Criterion, day: &str, name: &str, input: &str) {
+    let mut group = c.benchmark_group(format!("{}/{}", day, name));
+    let parsed = S::parse(input).unwrap();
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part_1", |b| b.iter(|| S::part_1(black_box(&parsed))));
+    group.bench_function("part_2", |b| b.iter(|| S::part_2(black_box(&parsed))));
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($c:ident, $($day:ident::$solution:ident),* $(,)?) => {
+        $(
+            let day = stringify!($day);
+            let input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+            let example = include_str!(concat!("../../", stringify!($day), "/example.txt"));
+
+            bench_day::<$day::$solution>($c, day, "input", input);
+            if day != "d03" {
+                bench_day::<$day::$solution>($c, day, "example", example);
+            }
+        )*
+    };
+}
+
+/// `Day03` only accepts 12 bit numbers, so the 5 bit example runs through
+/// the part functions directly.
+fn bench_d03_example(c: &mut Criterion) {
+    let example = include_str!("../../d03/example.txt");
+    let parse = |input: &str| -> Vec<BitVec<usize, Msb0>> {
+        aoc_common::lines(input)
+            .map(|line| line.chars().map(|c| c == '1').collect())
+            .collect()
+    };
+    let parsed = parse(example);
+
+    let mut group = c.benchmark_group("d03/example");
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(example))));
+    group.bench_function("part_1", |b| {
+        b.iter(|| d03::part_1::<_, _, 5>(black_box(&parsed).iter()))
+    });
+    group.bench_function("part_2", |b| {
+        b.iter(|| d03::part_2::<_, _, 5>(black_box(&parsed).iter()))
+    });
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_days!(
+        c,
+        d01::Day01,
+        d02::Day02,
+        d03::Day03,
+        d04::Day04,
+        d05::Day05,
+        d06::Day06,
+        d07::Day07,
+        d08::Day08,
+    );
+    bench_d03_example(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
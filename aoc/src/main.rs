@@ -0,0 +1,192 @@
+use aoc_common::{solve_profiled, CountingAllocator, Part, Profile, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
+use verify::{Answers, Check};
+
+mod output;
+mod timings;
+mod verify;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+type Solver = fn(&str, &[Part]) -> Result<Profile>;
+
+const DAYS: [Solver; 8] = [
+    solve_profiled::<d01::Day01>,
+    solve_profiled::<d02::Day02>,
+    solve_profiled::<d03::Day03>,
+    solve_profiled::<d04::Day04>,
+    solve_profiled::<d05::Day05>,
+    solve_profiled::<d06::Day06>,
+    solve_profiled::<d07::Day07>,
+    solve_profiled::<d08::Day08>,
+];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzles of a single or all days
+    Run(RunArgs),
+    /// Check every day against the expected answers
+    Verify(VerifyArgs),
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("days").required(true))]
+struct RunArgs {
+    /// day to solve
+    #[arg(short, long, group = "days", value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: Option<u8>,
+
+    /// solve all days, each on its dNN/input.txt
+    #[arg(short, long, group = "days", conflicts_with = "input")]
+    all: bool,
+
+    /// only solve this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// input file, `-` to read from stdin [default: dNN/input.txt]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// print wall time and peak heap allocation of every step
+    #[arg(short, long)]
+    timings: bool,
+
+    /// how to print the answers; json always includes timings
+    #[arg(short, long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Human,
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// file with the expected answers
+    #[arg(short, long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+/// The input of `day` in the workspace, wherever the runner is started from.
+fn default_input(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("d{:02}", day))
+        .join("input.txt")
+}
+
+fn run(args: &RunArgs) {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=DAYS.len() as u8,
+    };
+
+    let parts = match args.part {
+        Some(1) => &[Part::One][..],
+        Some(_) => &[Part::Two][..],
+        None => &Part::ALL[..],
+    };
+
+    let mut profiles = Vec::new();
+    let mut failed = false;
+    for day in days {
+        let path = args.input.clone().unwrap_or_else(|| default_input(day));
+
+        let input = match aoc_common::read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed = true;
+                continue;
+            }
+        };
+
+        match DAYS[usize::from(day) - 1](&input, parts) {
+            Ok(profile) => profiles.push((day, profile)),
+            Err(err) => {
+                let path = path.to_string_lossy();
+                eprintln!("error: {}", aoc_common::render_error(&path, &input, &err));
+                failed = true;
+            }
+        }
+    }
+
+    match args.format {
+        Format::Human if args.timings => print!(
+            "{}\n{}",
+            output::human(&profiles),
+            timings::table(&profiles)
+        ),
+        Format::Human => print!("{}", output::human(&profiles)),
+        Format::Json => print!("{}", output::json(&profiles)),
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// The answers to both parts of `input`, parsing it once. Only if that fails
+/// is every part solved on its own, to tell which one failed.
+fn solve_each(solver: Solver, input: &str) -> Vec<Result<String, String>> {
+    match solver(input, &Part::ALL) {
+        Ok(profile) => profile
+            .answers
+            .into_iter()
+            .map(|(answer, _)| Ok(answer.value))
+            .collect(),
+        Err(_) => Part::ALL
+            .iter()
+            .map(|&part| {
+                solver(input, &[part])
+                    .map(|mut profile| profile.answers.remove(0).0.value)
+                    .map_err(|err| err.to_string())
+            })
+            .collect(),
+    }
+}
+
+fn verify(args: &VerifyArgs) {
+    let path = args.answers.to_string_lossy();
+    let input = aoc_common::read_input(&args.answers)
+        .unwrap_or_else(|err| aoc_common::exit_with_error(err));
+    let answers = Answers::parse(&input, DAYS.len() as u8).unwrap_or_else(|err| {
+        aoc_common::exit_with_error(aoc_common::render_error(&path, &input, &err))
+    });
+
+    let mut checks = Vec::new();
+    for (day, solver) in (1..).zip(DAYS) {
+        let actual = match aoc_common::read_input(default_input(day)) {
+            Ok(input) => solve_each(solver, &input),
+            Err(err) => vec![Err(err.to_string()); Part::ALL.len()],
+        };
+        for (part, actual) in Part::ALL.into_iter().zip(actual) {
+            checks.push(Check::new(day, part, answers.get(day, part), actual));
+        }
+    }
+
+    print!("{}", verify::table(&checks));
+    if !checks.iter().all(Check::is_ok) {
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Verify(args) => verify(&args),
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::Profile;
+use serde_json::json;
+
+/// One line per answer, like `day 1 part 2: 1395`.
+pub fn human(profiles: &[(u8, Profile)]) -> String {
+    profiles
+        .iter()
+        .flat_map(|(day, profile)| {
+            profile
+                .answers
+                .iter()
+                .map(move |(answer, _)| format!("day {} {}\n", day, answer))
+        })
+        .collect()
+}
+
+/// A JSON array with an object per answer. Answers are strings, as not all of
+/// them are numbers that fit a JSON number; times are in nanoseconds.
+pub fn json(profiles: &[(u8, Profile)]) -> String {
+    let answers: Vec<_> = profiles
+        .iter()
+        .flat_map(|(day, profile)| {
+            profile.answers.iter().map(move |(answer, measurement)| {
+                json!({
+                    "day": day,
+                    "part": answer.part.number(),
+                    "answer": answer.value,
+                    "timing": {
+                        "parse_ns": profile.parse.elapsed.as_nanos() as u64,
+                        "part_ns": measurement.elapsed.as_nanos() as u64,
+                        "peak_bytes": measurement.peak_bytes,
+                    },
+                })
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&answers).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, Measurement, Part};
+    use std::time::Duration;
+
+    fn profiles() -> Vec<(u8, Profile)> {
+        let measurement = |nanos, peak_bytes| Measurement {
+            elapsed: Duration::from_nanos(nanos),
+            peak_bytes,
+        };
+        let answer = |part, value: &str| Answer {
+            part,
+            value: value.into(),
+        };
+
+        vec![
+            (
+                1,
+                Profile {
+                    parse: measurement(35, 2048),
+                    answers: vec![
+                        (answer(Part::One, "1451"), measurement(7, 4)),
+                        (answer(Part::Two, "1395"), measurement(9, 0)),
+                    ],
+                },
+            ),
+            (
+                6,
+                Profile {
+                    parse: measurement(6, 1024),
+                    answers: vec![(answer(Part::Two, "1765974267455"), measurement(4, 72))],
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(
+            human(&profiles()),
+            "day 1 part 1: 1451\nday 1 part 2: 1395\nday 6 part 2: 1765974267455\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let parsed: serde_json::Value = serde_json::from_str(&json(&profiles())).unwrap();
+
+        assert_eq!(
+            parsed,
+            json!([
+                {
+                    "day": 1,
+                    "part": 1,
+                    "answer": "1451",
+                    "timing": {"parse_ns": 35, "part_ns": 7, "peak_bytes": 4},
+                },
+                {
+                    "day": 1,
+                    "part": 2,
+                    "answer": "1395",
+                    "timing": {"parse_ns": 35, "part_ns": 9, "peak_bytes": 0},
+                },
+                {
+                    "day": 6,
+                    "part": 2,
+                    "answer": "1765974267455",
+                    "timing": {"parse_ns": 6, "part_ns": 4, "peak_bytes": 72},
+                },
+            ])
+        );
+    }
+}
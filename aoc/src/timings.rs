@@ -0,0 +1,108 @@
+use aoc_common::{Measurement, Profile};
+use std::time::Duration;
+
+/// Renders the measurements of every step of the given days as a plain text
+/// table.
+pub fn table(profiles: &[(u8, Profile)]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>10}  {:>10}\n",
+        "day", "step", "time", "peak"
+    );
+    let mut row = |day: u8, step: &str, measurement: &Measurement| {
+        table += &format!(
+            "{:>3}  {:<6}  {:>10}  {:>10}\n",
+            day,
+            step,
+            duration(measurement.elapsed),
+            bytes(measurement.peak_bytes)
+        );
+    };
+
+    for (day, profile) in profiles {
+        row(*day, "parse", &profile.parse);
+        for (answer, measurement) in &profile.answers {
+            row(*day, &format!("part {}", answer.part), measurement);
+        }
+    }
+    table
+}
+
+/// Formats `duration` in the largest fitting unit.
+pub fn duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.3} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.3} ms", nanos / 1e6)
+    } else {
+        format!("{:.3} s", nanos / 1e9)
+    }
+}
+
+/// Formats a number of bytes in the largest fitting binary unit.
+pub fn bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if value < 1024. {
+            break;
+        }
+        value /= 1024.;
+        unit = next;
+    }
+    format!("{:.1} {}", value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, Part};
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(duration(Duration::from_nanos(35_012)), "35.012 µs");
+        assert_eq!(duration(Duration::from_micros(2_812)), "2.812 ms");
+        assert_eq!(duration(Duration::from_millis(1_500)), "1.500 s");
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_table() {
+        let measurement = |micros, peak_bytes| Measurement {
+            elapsed: Duration::from_micros(micros),
+            peak_bytes,
+        };
+        let profile = Profile {
+            parse: measurement(35, 2048),
+            answers: vec![(
+                Answer {
+                    part: Part::Two,
+                    value: "1395".into(),
+                },
+                measurement(1, 0),
+            )],
+        };
+
+        assert_eq!(
+            table(&[(1, profile)]),
+            "day  step          time        peak\n  \
+               1  parse    35.000 µs     2.0 KiB\n  \
+               1  part 2    1.000 µs         0 B\n"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use aoc_common::{Error, ParseError, Part, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use toml::{Table, Value};
+
+/// Expected answers per day and part, as read from `answers.toml`:
+///
+/// ```toml
+/// [d01]
+/// part_1 = 1451
+/// part_2 = "1395"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(HashMap<(u8, Part), String>);
+
+impl Answers {
+    /// Parses the answers for days 1 to `days`, rejecting any other day and
+    /// days listed twice, like `[d1]` and `[d01]`.
+    pub fn parse(input: &str, days: u8) -> Result<Self> {
+        let table: Table = input.parse().map_err(|err: toml::de::Error| {
+            let offset = err.span().map_or(0, |span| span.start);
+            let (line, column) = position(input, offset);
+            ParseError::new(line, column, "", err.message())
+        })?;
+
+        let mut answers = HashMap::new();
+        let mut seen = HashMap::new();
+        for (key, parts) in &table {
+            let day: u8 = key
+                .strip_prefix('d')
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| invalid(input, key, "expected a day like `d01`"))?;
+            if !(1..=days).contains(&day) {
+                let message = format!("expected a day from `d01` to `d{:02}`", days);
+                return Err(invalid(input, key, message));
+            }
+            if let Some(previous) = seen.insert(day, key) {
+                let message = format!("same day as `{}`", previous);
+                return Err(invalid(input, key, message));
+            }
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| invalid(input, key, "expected a table of parts"))?;
+
+            for (name, value) in parts {
+                let part = match name.as_str() {
+                    "part_1" => Part::One,
+                    "part_2" => Part::Two,
+                    _ => return Err(invalid(input, name, "expected `part_1` or `part_2`")),
+                };
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Integer(i) => i.to_string(),
+                    _ => return Err(invalid(input, name, "expected a string or integer")),
+                };
+                answers.insert((day, part), value);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// 1-based line and column of a byte offset.
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+/// An error pointing at the first occurrence of `key` in the file.
+fn invalid(input: &str, key: &str, message: impl Display) -> Error {
+    let (line, column) = position(input, input.find(key).unwrap_or(0));
+    ParseError::new(line, column, key, message).into()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+            Status::Error(err) => write!(f, "ERROR: {}", err),
+        }
+    }
+}
+
+/// Result of checking one part of one day.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub status: Status,
+}
+
+impl Check {
+    /// Compares the `actual` answer, or the error that prevented it, with
+    /// the `expected` one.
+    pub fn new(
+        day: u8,
+        part: Part,
+        expected: Option<&str>,
+        actual: Result<String, String>,
+    ) -> Self {
+        let expected = expected.map(str::to_string);
+        let (actual, status) = match actual {
+            Ok(actual) => {
+                let status = match &expected {
+                    Some(expected) if *expected == actual => Status::Pass,
+                    Some(_) => Status::Fail,
+                    None => Status::Missing,
+                };
+                (Some(actual), status)
+            }
+            Err(err) => (None, Status::Error(err)),
+        };
+
+        Check {
+            day,
+            part,
+            expected,
+            actual,
+            status,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self.status, Status::Pass | Status::Missing)
+    }
+}
+
+/// Renders the checks as a plain text table.
+pub fn table(checks: &[Check]) -> String {
+    let width = |f: fn(&Check) -> Option<&String>| {
+        checks
+            .iter()
+            .filter_map(f)
+            .map(String::len)
+            .chain(Some("expected".len()))
+            .max()
+            .unwrap_or_default()
+    };
+    let expected_width = width(|c| c.expected.as_ref());
+    let actual_width = width(|c| c.actual.as_ref());
+
+    let mut table = format!(
+        "day  part  {:<ew$}  {:<aw$}  status\n",
+        "expected",
+        "actual",
+        ew = expected_width,
+        aw = actual_width
+    );
+    for check in checks {
+        table += &format!(
+            "{:>3}  {:>4}  {:<ew$}  {:<aw$}  {}\n",
+            check.day,
+            check.part.to_string(),
+            check.expected.as_deref().unwrap_or("-"),
+            check.actual.as_deref().unwrap_or("-"),
+            check.status,
+            ew = expected_width,
+            aw = actual_width
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "
+            [d01]
+            part_1 = 1451
+            part_2 = \"1395\"
+
+            [d08]
+            part_1 = 440
+            ",
+            8,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("1451"));
+        assert_eq!(answers.get(1, Part::Two), Some("1395"));
+        assert_eq!(answers.get(8, Part::One), Some("440"));
+        assert_eq!(answers.get(8, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        let err = |input| match Answers::parse(input, 8) {
+            Err(Error::Parse(err)) => err,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!(
+            err("[d01]\npart_3 = 1\n"),
+            ParseError::new(2, 1, "part_3", "expected `part_1` or `part_2`")
+        );
+        assert_eq!(
+            err("[day1]\npart_1 = 1\n"),
+            ParseError::new(1, 2, "day1", "expected a day like `d01`")
+        );
+        assert_eq!(
+            err("[d09]\npart_1 = 1\n"),
+            ParseError::new(1, 2, "d09", "expected a day from `d01` to `d08`")
+        );
+        assert_eq!(
+            err("[d00]\npart_1 = 1\n").message,
+            "expected a day from `d01` to `d08`"
+        );
+        assert_eq!(
+            err("[d01]\npart_1 = 1\n[d1]\npart_2 = 2\n"),
+            ParseError::new(3, 2, "d1", "same day as `d01`")
+        );
+        assert_eq!(err("[d01]\npart_1 = [1]\n").line, 2);
+        assert_eq!(err("[d01]\npart_1 = \n").line, 2);
+    }
+
+    #[test]
+    fn test_check_status() {
+        let check = |expected, actual| Check::new(1, Part::One, expected, actual).status;
+
+        assert_eq!(check(Some("7"), Ok("7".into())), Status::Pass);
+        assert_eq!(check(Some("7"), Ok("8".into())), Status::Fail);
+        assert_eq!(check(None, Ok("8".into())), Status::Missing);
+        assert!(matches!(
+            check(Some("7"), Err("no solution".into())),
+            Status::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_table() {
+        let checks = [
+            Check::new(1, Part::One, Some("7"), Ok("7".into())),
+            Check::new(1, Part::Two, Some("5"), Ok("1234567890".into())),
+            Check::new(2, Part::One, None, Ok("150".into())),
+        ];
+
+        assert_eq!(
+            table(&checks),
+            "day  part  expected  actual      status\n  \
+               1     1  7         7           pass\n  \
+               1     2  5         1234567890  FAIL\n  \
+               2     1  -         150         missing\n"
+        );
+    }
+}
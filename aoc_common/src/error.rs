@@ -0,0 +1,180 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("could not read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("could not read input: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    /// More than one problem in the input: the first and all others, in
+    /// order of appearance.
+    #[error("{} problems in the input, the first at {}", .1.len() + 1, .0)]
+    ParseMany(ParseError, Vec<ParseError>),
+    #[error("no solution: {0}")]
+    NoSolution(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A problem in the puzzle input, with the 1-based line and column of the
+/// offending text.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: impl Into<String>, message: impl Display) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats `err` for the terminal. Parse errors also show the offending line
+/// of `input`, with the text in question underlined.
+pub fn render_error(path: &str, input: &str, err: &Error) -> String {
+    match err {
+        Error::Parse(err) => render_parse_error(path, input, err),
+        Error::ParseMany(first, rest) => std::iter::once(first)
+            .chain(rest)
+            .map(|err| render_parse_error(path, input, err))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => format!("{}: {}", path, err),
+    }
+}
+
+fn render_parse_error(path: &str, input: &str, err: &ParseError) -> String {
+    let mut rendered = format!("{}:{}:{}: {}", path, err.line, err.column, err.message);
+    if let Some(line) = input.lines().nth(err.line.saturating_sub(1)) {
+        let number = err.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(err.column.saturating_sub(1));
+        let underline = "^".repeat(err.text.chars().count().max(1));
+        rendered += &format!(
+            "\n{} |\n{} | {}\n{} | {}{}",
+            gutter, number, line, gutter, indent, underline
+        );
+    }
+    rendered
+}
+
+/// Collects all values, or all errors if there are any, so the input can be
+/// fixed in one go.
+pub fn collect_parsed<T>(
+    results: impl IntoIterator<Item = Result<T, ParseError>>,
+) -> Result<Vec<T>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    let mut errors = errors.into_iter();
+    match (errors.next(), errors.collect::<Vec<_>>()) {
+        (None, _) => Ok(values),
+        (Some(err), rest) if rest.is_empty() => Err(Error::Parse(err)),
+        (Some(first), rest) => Err(Error::ParseMany(first, rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_parse_error() {
+        let err = Error::Parse(ParseError::new(
+            2,
+            5,
+            "62a",
+            "invalid digit found in string",
+        ));
+        assert_eq!(
+            render_error("d05/input.txt", "0,9 -> 5,9\n8,0 -> 62a,8\n", &err),
+            "d05/input.txt:2:5: invalid digit found in string\n  \
+               |\n\
+             2 | 8,0 -> 62a,8\n  \
+               |     ^^^"
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 2, column 5: invalid digit found in string: `62a`"
+        );
+    }
+
+    #[test]
+    fn test_render_many_parse_errors() {
+        let err = collect_parsed(vec![
+            Ok(1),
+            Err(ParseError::new(2, 1, "x", "invalid digit found in string")),
+            Ok(3),
+            Err(ParseError::new(4, 3, "y", "invalid digit found in string")),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "2 problems in the input, the first at line 2, column 1: \
+             invalid digit found in string: `x`"
+        );
+        assert_eq!(
+            render_error("in.txt", "1\nx\n3\n1 y\n", &err),
+            "in.txt:2:1: invalid digit found in string\n  \
+               |\n\
+             2 | x\n  \
+               | ^\n\
+             in.txt:4:3: invalid digit found in string\n  \
+               |\n\
+             4 | 1 y\n  \
+               |   ^"
+        );
+    }
+
+    #[test]
+    fn test_collect_parsed() {
+        assert_eq!(collect_parsed(vec![Ok(1), Ok(2)]).unwrap(), vec![1, 2]);
+        assert!(matches!(
+            collect_parsed(vec![Ok(1), Err(ParseError::new(1, 1, "", "bad"))]),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_render_other_errors() {
+        let err = Error::NoSolution("no board has won".into());
+        assert_eq!(
+            render_error("d04/input.txt", "", &err),
+            "d04/input.txt: no solution: no board has won"
+        );
+    }
+}
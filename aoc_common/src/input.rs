@@ -0,0 +1,199 @@
+use crate::error::{Error, ParseError, Result};
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Opens `path` for reading it line by line, `-` being stdin.
+pub fn open_input(path: impl AsRef<Path>) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = fs::File::open(path).map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(Box::new(io::BufReader::new(file)))
+}
+
+/// Reads a whole puzzle input into memory, from stdin when `path` is `-`.
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let read = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    read.map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Iterates over the non-empty lines of an input, trimmed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    numbered_lines(input).map(|line| line.text)
+}
+
+/// Like [`lines`], but keeps track of where each line is in the input, so
+/// parse errors can point to it.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, raw)| InputLine::new(i + 1, raw))
+        .filter(|line| !line.text.is_empty())
+}
+
+/// Parses every non-empty line of an input into a `T`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbered_lines(input)
+        .map(|line| line.parse(line.text))
+        .collect()
+}
+
+/// Parses an input consisting of a single line of values divided by
+/// `separator`, like `3,4,3,1,2`.
+pub fn parse_separated<T>(input: &str, separator: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut lines = numbered_lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "input is empty"))?;
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "expected a single line"));
+    }
+
+    line.parse_separated(separator)
+}
+
+/// A single line of an input, with its 1-based line number.
+#[derive(Clone, Copy, Debug)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    /// The line without surrounding whitespace.
+    pub text: &'a str,
+    raw: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(number: usize, raw: &'a str) -> Self {
+        InputLine {
+            number,
+            text: raw.trim(),
+            raw,
+        }
+    }
+
+    /// 1-based column at which `part`, a slice of this line, starts.
+    pub fn column(&self, part: &str) -> usize {
+        let start = self.raw.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.raw.len() {
+            self.raw[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// An error pointing at `part`, which should be a slice of this line.
+    pub fn error(&self, part: &str, message: impl Display) -> ParseError {
+        ParseError::new(self.number, self.column(part), part, message)
+    }
+
+    /// Parses `part`, a slice of this line, reporting failures at its position.
+    pub fn parse<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let part = part.trim();
+        part.parse().map_err(|err| self.error(part, err))
+    }
+
+    /// Parses the values of this line divided by `separator`.
+    pub fn parse_separated<T>(&self, separator: char) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .split(separator)
+            .map(|value| self.parse(value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_skips_empty() {
+        let input = "
+            1
+            2
+
+            3
+        ";
+        assert_eq!(lines(input).collect::<Vec<_>>(), vec!["1", "2", "3"]);
+        assert_eq!(
+            numbered_lines(input)
+                .map(|line| line.number)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 5]
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines::<i32>("199\n200\n208\n"),
+            Ok(vec![199, 200, 208])
+        );
+        assert_eq!(
+            parse_lines::<i32>("199\n\n  abc\n"),
+            Err(ParseError::new(
+                3,
+                3,
+                "abc",
+                "invalid digit found in string"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_separated() {
+        assert_eq!(
+            parse_separated::<u16>("3,4,3,1,2\n", ','),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+        assert_eq!(
+            parse_separated::<u16>("3,4, x,1\n", ',').unwrap_err(),
+            ParseError::new(1, 6, "x", "invalid digit found in string")
+        );
+        assert_eq!(parse_separated::<u16>("3,4\n5\n", ',').unwrap_err().line, 2);
+        assert_eq!(parse_separated::<u16>("\n", ',').unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_column_counts_chars() {
+        let line = InputLine::new(7, "ä -> 12");
+        let (_, rhs) = line.text.split_once("->").unwrap();
+        assert_eq!(line.parse::<i32>(rhs), Ok(12));
+        assert_eq!(line.error(rhs.trim(), "x").column, 6);
+        assert_eq!(line.error("elsewhere", "x").column, 1);
+    }
+}
@@ -0,0 +1,38 @@
+mod error;
+mod input;
+mod profile;
+mod solution;
+
+pub use error::{collect_parsed, render_error, Error, ParseError, Result};
+pub use input::{
+    lines, numbered_lines, open_input, parse_lines, parse_separated, read_input, InputLine,
+};
+pub use profile::{measure, solve_profiled, CountingAllocator, Measurement, Profile};
+pub use solution::{solve, Answer, Part, Solution};
+
+use std::env;
+use std::fmt::Display;
+use std::process;
+
+/// Prints `message` to stderr and exits with a non-zero status.
+pub fn exit_with_error(message: impl Display) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+/// Entry point of the single-day binaries: solves both parts for the input
+/// file given as first argument, `input.txt` when there is none.
+pub fn run<S: Solution>() {
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".into());
+    run_file::<S>(&path);
+}
+
+/// Solves both parts for the input file at `path`, `-` being stdin.
+pub fn run_file<S: Solution>(path: &str) {
+    let input = read_input(path).unwrap_or_else(|err| exit_with_error(err));
+
+    match solve::<S>(&input, &Part::ALL) {
+        Ok(answers) => answers.iter().for_each(|answer| println!("{}", answer)),
+        Err(err) => exit_with_error(render_error(path, &input, &err)),
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::Result;
+use crate::solution::{Answer, Part, Solution};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that keeps track of the bytes currently allocated and
+/// their peak. Binaries opt in with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc_common::CountingAllocator = aoc_common::CountingAllocator;
+/// ```
+///
+/// Without it, all measured peaks are 0.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(by: usize) {
+        let current = CURRENT.fetch_add(by, Ordering::Relaxed) + by;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(by: usize) {
+        CURRENT.fetch_sub(by, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Wall time of a step and the most heap memory it had allocated at once,
+/// on top of what was allocated before it started.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub peak_bytes: usize,
+}
+
+/// Runs `f`, measuring it.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let before = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let start = Instant::now();
+
+    let result = f();
+
+    let measurement = Measurement {
+        elapsed: start.elapsed(),
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(before),
+    };
+    (result, measurement)
+}
+
+/// Answers of a day along with the measurements of every step.
+#[derive(Debug)]
+pub struct Profile {
+    pub parse: Measurement,
+    pub answers: Vec<(Answer, Measurement)>,
+}
+
+/// Like [`solve`](crate::solve), but measures parsing and every part.
+pub fn solve_profiled<S: Solution>(input: &str, parts: &[Part]) -> Result<Profile> {
+    let (parsed, parse) = measure(|| S::parse(input));
+    let parsed = parsed?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let (value, measurement) = measure(|| match part {
+                Part::One => S::part_1(&parsed).map(|answer| answer.to_string()),
+                Part::Two => S::part_2(&parsed).map(|answer| answer.to_string()),
+            });
+            Ok((
+                Answer {
+                    part,
+                    value: value?,
+                },
+                measurement,
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Profile { parse, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (value, measurement) = measure(|| {
+            std::thread::sleep(Duration::from_millis(2));
+            42
+        });
+        assert_eq!(value, 42);
+        assert!(measurement.elapsed >= Duration::from_millis(2));
+
+        // the test binary doesn't install the allocator, so drive it directly
+        let layout = Layout::array::<u64>(1024).unwrap();
+        let (_, measurement) = measure(|| unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 2 * layout.size());
+            CountingAllocator.dealloc(ptr, Layout::array::<u64>(2048).unwrap());
+        });
+        assert_eq!(measurement.peak_bytes, 2 * layout.size());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::Result;
+use std::fmt::{self, Display};
+
+/// A single day of the calendar: how to parse its input and how to solve
+/// both parts on the parsed input.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part {}: {}", self.part, self.value)
+    }
+}
+
+/// Parses `input` for `S` and solves the given parts.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+    let profile = crate::solve_profiled::<S>(input, parts)?;
+    Ok(profile
+        .answers
+        .into_iter()
+        .map(|(answer, _)| answer)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = i32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(crate::parse_lines(input)?)
+        }
+
+        fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+            input
+                .iter()
+                .max()
+                .copied()
+                .ok_or_else(|| Error::NoSolution("empty input".into()))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = solve::<Sum>("1\n2\n3\n", &Part::ALL).unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                Answer {
+                    part: Part::One,
+                    value: "6".into()
+                },
+                Answer {
+                    part: Part::Two,
+                    value: "3".into()
+                },
+            ]
+        );
+        assert_eq!(answers[1].to_string(), "part 2: 3");
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        let answers = solve::<Sum>("1\n2\n3\n", &[Part::Two]).unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert!(matches!(
+            solve::<Sum>("1\nx\n", &Part::ALL),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            solve::<Sum>("", &[Part::Two]),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
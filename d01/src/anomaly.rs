@@ -0,0 +1,145 @@
+/// How unusual a change between two readings has to be to count as part of
+/// a spike.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Threshold {
+    /// Standard deviations from the mean change.
+    ZScore(f64),
+    /// Median absolute deviations from the median change, scaled by 1.4826
+    /// to be comparable to a z-score. Unlike the z-score, this isn't skewed
+    /// by the spikes themselves.
+    Mad(f64),
+}
+
+/// Indices of readings that jump away from the previous reading and right
+/// back on the next one, where both changes exceed `threshold` compared to
+/// all changes in `input`.
+pub fn find_spikes(input: &[i32], threshold: Threshold) -> Vec<usize> {
+    let changes: Vec<f64> = input
+        .windows(2)
+        .map(|pair| f64::from(pair[1]) - f64::from(pair[0]))
+        .collect();
+    if changes.is_empty() {
+        return Vec::new();
+    }
+
+    let (center, scale, limit) = match threshold {
+        Threshold::ZScore(limit) => {
+            let mean = changes.iter().sum::<f64>() / changes.len() as f64;
+            let variance =
+                changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / changes.len() as f64;
+            (mean, variance.sqrt(), limit)
+        }
+        Threshold::Mad(limit) => {
+            let center = median(changes.clone());
+            let deviations = changes.iter().map(|c| (c - center).abs()).collect();
+            (center, 1.4826 * median(deviations), limit)
+        }
+    };
+    let unusual = |change: f64| {
+        let deviation = (change - center).abs();
+        if scale == 0. {
+            deviation > 0.
+        } else {
+            deviation / scale > limit
+        }
+    };
+
+    changes
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] * pair[1] < 0.)
+        .filter(|(_, pair)| unusual(pair[0]) && unusual(pair[1]))
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.
+    } else {
+        values[mid]
+    }
+}
+
+/// `input` with every spike replaced by the midpoint of its neighbours, so
+/// the indices of all other readings stay the same. Spikes are cleaned from
+/// left to right, so a spike right after another one uses the cleaned value.
+/// A spike at either end takes the value of its only neighbour, and indices
+/// past the end are ignored.
+pub fn remove_spikes(input: &[i32], spikes: &[usize]) -> Vec<i32> {
+    let mut cleaned = input.to_vec();
+    for &i in spikes.iter().filter(|&&i| i < input.len()) {
+        let before = i.checked_sub(1).map(|j| cleaned[j]);
+        let after = input.get(i + 1).copied();
+        cleaned[i] = match (before, after) {
+            (Some(before), Some(after)) => ((i64::from(before) + i64::from(after)) / 2) as i32,
+            (Some(only), None) | (None, Some(only)) => only,
+            (None, None) => continue,
+        };
+    }
+    cleaned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn with_spikes() -> Vec<i32> {
+        let mut input: Vec<i32> = (0..100).map(|i| 1000 + i * 3 + i % 4).collect();
+        input[20] += 400;
+        input[61] -= 300;
+        input
+    }
+
+    #[test]
+    fn test_find_spikes() {
+        let input = with_spikes();
+
+        assert_eq!(find_spikes(&input, Threshold::Mad(5.)), vec![20, 61]);
+        assert_eq!(find_spikes(&input, Threshold::ZScore(3.)), vec![20, 61]);
+        assert!(find_spikes(&input, Threshold::ZScore(10.)).is_empty());
+    }
+
+    #[test]
+    fn test_no_spikes() {
+        assert!(find_spikes(TEST_DATA, Threshold::Mad(5.)).is_empty());
+        assert!(find_spikes(&[], Threshold::Mad(5.)).is_empty());
+        assert!(find_spikes(&[5], Threshold::ZScore(3.)).is_empty());
+        assert!(find_spikes(&[5, 5, 5, 5], Threshold::Mad(3.)).is_empty());
+        // a step that doesn't reverse isn't a spike
+        assert!(find_spikes(&[1, 1, 1, 1, 9, 9, 9, 9], Threshold::Mad(3.)).is_empty());
+    }
+
+    #[test]
+    fn test_remove_spikes() {
+        let input = with_spikes();
+        let cleaned = remove_spikes(&input, &find_spikes(&input, Threshold::Mad(5.)));
+
+        assert_eq!(cleaned.len(), input.len());
+        assert_eq!(cleaned[20], (input[19] + input[21]) / 2);
+        assert_eq!(cleaned[61], (input[60] + input[62]) / 2);
+        assert!(find_spikes(&cleaned, Threshold::Mad(5.)).is_empty());
+        // each spike had turned one of its steps into a decrease
+        assert_eq!(crate::part_1(&cleaned), crate::part_1(&input) + 2);
+    }
+
+    #[test]
+    fn test_remove_spikes_at_the_ends() {
+        let input = [900, 200, 208, 210, -50];
+        assert_eq!(
+            remove_spikes(&input, &[0, 4]),
+            vec![200, 200, 208, 210, 210]
+        );
+        assert_eq!(
+            remove_spikes(&input, &[0, 1]),
+            vec![200, 204, 208, 210, -50]
+        );
+        assert_eq!(remove_spikes(&input, &[5, 9]), input);
+        assert_eq!(remove_spikes(&[7], &[0]), vec![7]);
+        assert!(remove_spikes(&[], &[0]).is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+use aoc_common::{Error, ParseError, Result};
+use std::convert::Infallible;
+use std::io::Read;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A column of a CSV file, by its header or its 0-based index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+impl FromStr for Column {
+    type Err = Infallible;
+
+    /// Numbers select by index, anything else by name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse() {
+            Ok(index) => Column::Index(index),
+            Err(_) => Column::Name(s.into()),
+        })
+    }
+}
+
+/// Why [`read_channels`] could not read a file.
+#[derive(Error, Debug)]
+pub enum ChannelError {
+    /// The file itself is unreadable or not valid CSV with integer depths.
+    #[error(transparent)]
+    Input(#[from] Error),
+    #[error("no column named `{0}`")]
+    UnknownName(String),
+    #[error("no column {index}, there are only {count}")]
+    OutOfRange { index: usize, count: usize },
+    #[error("no records to pick the integer columns from")]
+    NoRecords,
+    #[error("no column contains only integers")]
+    NoIntegerColumn,
+}
+
+/// The depths of one column of a CSV file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Channel {
+    pub name: String,
+    pub depths: Vec<i32>,
+}
+
+/// Reads the given `columns` of a CSV file with a header. Without any
+/// columns given, reads all columns that only contain integers, which skips
+/// timestamps and the like, which needs at least one record.
+pub fn read_channels(reader: impl Read, columns: &[Column]) -> Result<Vec<Channel>, ChannelError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+    let headers = reader.headers().map_err(csv_error)?.clone();
+
+    let mut records = Vec::new();
+    for record in reader.records() {
+        records.push(record.map_err(csv_error)?);
+    }
+
+    let indices: Vec<usize> = if columns.is_empty() {
+        if records.is_empty() {
+            return Err(ChannelError::NoRecords);
+        }
+        let indices: Vec<usize> = (0..headers.len())
+            .filter(|&i| {
+                records
+                    .iter()
+                    .all(|record| record.get(i).is_some_and(|v| v.parse::<i32>().is_ok()))
+            })
+            .collect();
+        if indices.is_empty() {
+            return Err(ChannelError::NoIntegerColumn);
+        }
+        indices
+    } else {
+        columns
+            .iter()
+            .map(|column| match column {
+                Column::Index(i) if *i < headers.len() => Ok(*i),
+                Column::Index(i) => Err(ChannelError::OutOfRange {
+                    index: *i,
+                    count: headers.len(),
+                }),
+                Column::Name(name) => headers
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or_else(|| ChannelError::UnknownName(name.clone())),
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    indices
+        .into_iter()
+        .map(|i| {
+            let depths = records
+                .iter()
+                .map(|record| {
+                    let value = record.get(i).unwrap_or_default();
+                    value.parse().map_err(|err| {
+                        let line = record.position().map_or(0, |p| p.line() as usize);
+                        ParseError::new(line, column_of(record, i), value, err)
+                    })
+                })
+                .collect::<Result<_, ParseError>>()
+                .map_err(Error::from)?;
+            Ok(Channel {
+                name: headers[i].to_string(),
+                depths,
+            })
+        })
+        .collect()
+}
+
+/// 1-based character column of field `i`, exact for unquoted fields.
+fn column_of(record: &csv::StringRecord, i: usize) -> usize {
+    record
+        .iter()
+        .take(i)
+        .map(|field| field.chars().count() + 1)
+        .sum::<usize>()
+        + 1
+}
+
+fn csv_error(err: csv::Error) -> Error {
+    if !err.is_io_error() {
+        let line = err.position().map_or(0, |p| p.line() as usize);
+        return ParseError::new(line, 1, "", err).into();
+    }
+    match err.into_kind() {
+        csv::ErrorKind::Io(err) => Error::Io(err),
+        _ => unreachable!(),
+    }
+}
+
+/// Increase counts of every channel as a plain text table.
+pub fn channel_table(channels: &[Channel]) -> String {
+    let width = channels
+        .iter()
+        .map(|channel| channel.name.chars().count())
+        .chain(Some("channel".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:<width$}  {:>8}  {:>8}  {:>8}\n",
+        "channel",
+        "readings",
+        "part 1",
+        "part 2",
+        width = width
+    );
+    for channel in channels {
+        table += &format!(
+            "{:<width$}  {:>8}  {:>8}  {:>8}\n",
+            channel.name,
+            channel.depths.len(),
+            crate::part_1(&channel.depths),
+            crate::part_2(&channel.depths),
+            width = width
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+timestamp,port,starboard
+2021-12-01T00:00:00,199,200
+2021-12-01T00:00:01,200,190
+2021-12-01T00:00:02,208,180
+2021-12-01T00:00:03,210,185
+2021-12-01T00:00:04,200,170
+";
+
+    fn err(columns: &[Column], csv: &str) -> ParseError {
+        match read_channels(csv.as_bytes(), columns) {
+            Err(ChannelError::Input(Error::Parse(err))) => err,
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!("2".parse(), Ok(Column::Index(2)));
+        assert_eq!("port".parse(), Ok(Column::Name("port".into())));
+    }
+
+    #[test]
+    fn test_read_channels() {
+        let channels = read_channels(CSV.as_bytes(), &[]).unwrap();
+        assert_eq!(
+            channels,
+            vec![
+                Channel {
+                    name: "port".into(),
+                    depths: vec![199, 200, 208, 210, 200],
+                },
+                Channel {
+                    name: "starboard".into(),
+                    depths: vec![200, 190, 180, 185, 170],
+                },
+            ]
+        );
+
+        let channels = read_channels(
+            CSV.as_bytes(),
+            &[Column::Index(2), Column::Name("port".into())],
+        )
+        .unwrap();
+        let names: Vec<_> = channels.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["starboard", "port"]);
+    }
+
+    #[test]
+    fn test_column_errors() {
+        let err = |columns: &[Column], csv: &str| {
+            read_channels(csv.as_bytes(), columns)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err(&[Column::Name("bow".into())], CSV),
+            "no column named `bow`"
+        );
+        assert_eq!(
+            err(&[Column::Index(3)], CSV),
+            "no column 3, there are only 3"
+        );
+        assert_eq!(err(&[], "t\nnow\n"), "no column contains only integers");
+        assert!(matches!(
+            read_channels("t,port\n".as_bytes(), &[]),
+            Err(ChannelError::NoRecords)
+        ));
+        // selected columns can be empty
+        let empty = read_channels("t,port\n".as_bytes(), &[Column::Index(1)]).unwrap();
+        assert!(empty[0].depths.is_empty());
+    }
+
+    #[test]
+    fn test_read_channels_errors() {
+        assert_eq!(
+            err(&[Column::Index(0)], CSV),
+            ParseError::new(2, 1, "2021-12-01T00:00:00", "invalid digit found in string")
+        );
+        assert_eq!(
+            err(&[Column::Name("port".into())], "t,port\n1,199\n2,2x0\n"),
+            ParseError::new(3, 3, "2x0", "invalid digit found in string")
+        );
+        assert_eq!(err(&[], "t,port\n1,199\n2\n").line, 3);
+    }
+
+    #[test]
+    fn test_channel_table() {
+        let channels = read_channels(CSV.as_bytes(), &[]).unwrap();
+
+        assert_eq!(
+            channel_table(&channels),
+            "channel    readings    part 1    part 2\n\
+             port              5         3         1\n\
+             starboard         5         1         0\n"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+/// Parameters of a synthetic sonar sweep.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratorOptions {
+    /// Number of readings.
+    pub length: usize,
+    /// Depth of the first reading.
+    pub start: i32,
+    /// Average change from one reading to the next.
+    pub trend: f64,
+    /// Readings vary by up to this much around the trend.
+    pub noise: f64,
+    /// Chance of a reading being a spike that reverses on the next reading.
+    pub spike_rate: f64,
+    pub seed: u64,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions {
+            length: 2000,
+            start: 150,
+            trend: 3.,
+            noise: 10.,
+            spike_rate: 0.,
+            seed: 0,
+        }
+    }
+}
+
+/// SplitMix64, so the same seed gives the same sweep on every platform and
+/// with every version.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Generates a depth series following `options`. Depths never go below 0.
+pub fn generate(options: &GeneratorOptions) -> Vec<i32> {
+    let mut rng = Rng(options.seed);
+    let mut depth = f64::from(options.start);
+    let spike_height = 5. * options.noise + 50.;
+
+    (0..options.length)
+        .map(|i| {
+            if i > 0 {
+                depth =
+                    (depth + options.trend + options.noise * (2. * rng.next_f64() - 1.)).max(0.);
+            }
+
+            let mut reading = depth;
+            if rng.next_f64() < options.spike_rate {
+                let direction = if rng.next_f64() < 0.5 { -1. } else { 1. };
+                reading = (reading + direction * spike_height).max(0.);
+            }
+            reading.round() as i32
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        let options = GeneratorOptions {
+            spike_rate: 0.1,
+            ..GeneratorOptions::default()
+        };
+
+        assert_eq!(generate(&options), generate(&options));
+        assert_ne!(
+            generate(&options),
+            generate(&GeneratorOptions {
+                seed: 1,
+                ..options.clone()
+            })
+        );
+        assert_eq!(generate(&options).len(), 2000);
+        assert_eq!(generate(&options)[0], 150);
+    }
+
+    #[test]
+    fn test_generate_follows_options() {
+        let steady = generate(&GeneratorOptions {
+            length: 10,
+            start: 100,
+            trend: 2.,
+            noise: 0.,
+            ..GeneratorOptions::default()
+        });
+        assert_eq!(
+            steady,
+            vec![100, 102, 104, 106, 108, 110, 112, 114, 116, 118]
+        );
+        assert_eq!(crate::part_1(&steady), 9);
+        assert_eq!(crate::part_2(&steady), 7);
+
+        let falling = generate(&GeneratorOptions {
+            trend: -5.,
+            noise: 0.,
+            length: 100,
+            ..GeneratorOptions::default()
+        });
+        assert_eq!(falling[29], 5);
+        assert_eq!(falling[99], 0);
+    }
+
+    #[test]
+    fn test_generate_spikes() {
+        let options = GeneratorOptions {
+            trend: 1.,
+            noise: 2.,
+            spike_rate: 0.02,
+            ..GeneratorOptions::default()
+        };
+        let depths = generate(&options);
+        let spikes = crate::find_spikes(&depths, crate::Threshold::Mad(5.));
+
+        // around 2% of 2000, minus the ones right next to each other
+        assert!((20..=50).contains(&spikes.len()), "{} spikes", spikes.len());
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_common::{Result, Solution};
+use std::ops::Add;
+
+mod anomaly;
+mod channels;
+mod generate;
+mod parallel;
+mod plot;
+mod stats;
+mod stream;
+
+pub use anomaly::{find_spikes, remove_spikes, Threshold};
+pub use channels::{channel_table, read_channels, Channel, ChannelError, Column};
+pub use generate::{generate, GeneratorOptions};
+pub use parallel::{count_increases_parallel, part_1_parallel, part_2_parallel, CHUNK_SIZE};
+pub use plot::{plot_svg, PlotOptions, MIN_PLOT_SIZE};
+pub use stats::{DepthStats, Jump, Run};
+pub use stream::{stream, StreamCounter};
+
+/// Day 1: Sonar Sweep
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(aoc_common::parse_lines(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(part_2(input))
+    }
+}
+
+/// A depth reading: any number that can be added and compared, like `i32`,
+/// `u64` or `f64`.
+pub trait Depth: Copy + PartialOrd + Add<Output = Self> {}
+
+impl<T: Copy + PartialOrd + Add<Output = T>> Depth for T {}
+
+/// Counts how often the sum of a `window`-measurement window is larger than
+/// the sum of the previous window.
+///
+/// Two neighbouring windows share all but their first and last measurement,
+/// so this only compares those, in O(n) for any width. As no sums are built,
+/// they can't overflow either. Floats aren't `Ord`, they need a tolerance
+/// with [`count_increases_with_tolerance`].
+///
+/// # Panics
+///
+/// If `window` is 0.
+pub fn count_increases<T: Ord>(input: &[T], window: usize) -> usize {
+    count_where(input, window, |first, last| last > first)
+}
+
+/// Like [`count_increases`], but a window only counts as larger when it
+/// exceeds the previous one by more than `tolerance`, to ignore noise.
+///
+/// # Panics
+///
+/// If `window` is 0, or in debug builds, if adding `tolerance` to an
+/// integer measurement overflows.
+pub fn count_increases_with_tolerance<T: Depth>(input: &[T], window: usize, tolerance: T) -> usize {
+    count_where(input, window, |&first, &last| last > first + tolerance)
+}
+
+fn count_where<T>(input: &[T], window: usize, increased: impl Fn(&T, &T) -> bool) -> usize {
+    assert!(window > 0, "window must not be empty");
+
+    input
+        .iter()
+        .zip(input.iter().skip(window))
+        .filter(|&(first, last)| increased(first, last))
+        .count()
+}
+
+/// Counts how often a depth measurement is larger than the previous one.
+pub fn part_1<T: Ord>(input: &[T]) -> usize {
+    count_increases(input, 1)
+}
+
+/// Counts how often the sum of a three-measurement window is larger than the
+/// sum of the previous window.
+pub fn part_2<T: Ord>(input: &[T]) -> usize {
+    count_increases(input, 3)
+}
+
+/// [`part_1`] for any [`Depth`], like `f64`, only counting increases by more
+/// than `tolerance`.
+pub fn part_1_with_tolerance<T: Depth>(input: &[T], tolerance: T) -> usize {
+    count_increases_with_tolerance(input, 1, tolerance)
+}
+
+/// [`part_2`] for any [`Depth`], like `f64`, only counting increases by more
+/// than `tolerance`.
+pub fn part_2_with_tolerance<T: Depth>(input: &[T], tolerance: T) -> usize {
+    count_increases_with_tolerance(input, 3, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn part_1_works() {
+        assert_eq!(part_1(TEST_DATA), 7);
+    }
+
+    #[test]
+    fn part_2_works() {
+        assert_eq!(part_2(TEST_DATA), 5);
+    }
+
+    /// How often the sum of a `window`-measurement window is larger than the
+    /// sum of the previous one, summing every window from scratch.
+    fn naive(input: &[i32], window: usize) -> usize {
+        let sums: Vec<i32> = (0..(input.len() + 1).saturating_sub(window))
+            .map(|start| input[start..start + window].iter().sum())
+            .collect();
+
+        let mut count = 0;
+        for i in 1..sums.len() {
+            if sums[i] > sums[i - 1] {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn count_increases_works() {
+        assert_eq!(count_increases(TEST_DATA, 1), 7);
+        assert_eq!(count_increases(TEST_DATA, 3), 5);
+        assert_eq!(count_increases(TEST_DATA, 10), 0);
+        assert_eq!(count_increases(TEST_DATA, 11), 0);
+        assert_eq!(count_increases::<i32>(&[], 2), 0);
+    }
+
+    #[test]
+    fn other_integer_types() {
+        let wide: Vec<i64> = TEST_DATA.iter().map(|&d| i64::from(d)).collect();
+        assert_eq!(part_1(&wide), 7);
+        assert_eq!(part_2(&wide), 5);
+
+        let unsigned: Vec<u64> = TEST_DATA.iter().map(|&d| d as u64).collect();
+        assert_eq!(part_2(&unsigned), 5);
+
+        // window sums of these don't fit an i32, or even an i64
+        let deep = [i32::MAX - 2, i32::MAX, i32::MAX - 1, i32::MAX];
+        assert_eq!(part_1(&deep), 2);
+        assert_eq!(part_2(&deep), 1);
+        let deeper = [i64::MAX, i64::MAX - 1, i64::MAX, i64::MAX];
+        assert_eq!(part_1(&deeper), 1);
+        assert_eq!(count_increases(&deeper, 2), 1);
+    }
+
+    #[test]
+    fn floats_with_tolerance() {
+        let readings = [199.0, 199.04, 208.2, 208.21, 200.0, 207.5];
+
+        assert_eq!(count_increases_with_tolerance(&readings, 1, 0.0), 4);
+        assert_eq!(count_increases_with_tolerance(&readings, 1, 0.05), 2);
+        assert_eq!(count_increases_with_tolerance(&readings, 2, 0.05), 2);
+        assert_eq!(count_increases_with_tolerance(&[1, 3, 4, 4], 1, 1), 1);
+
+        let floats: Vec<f64> = TEST_DATA.iter().map(|&d| f64::from(d) + 0.5).collect();
+        assert_eq!(part_1_with_tolerance(&floats, 0.0), 7);
+        assert_eq!(part_2_with_tolerance(&floats, 0.0), 5);
+        assert_eq!(part_1_with_tolerance(&readings, 0.05), 2);
+        assert_eq!(part_2_with_tolerance(&readings, 0.05), 2);
+        assert_eq!(part_2_with_tolerance(TEST_DATA, 0), part_2(TEST_DATA));
+    }
+
+    #[test]
+    #[should_panic(expected = "window must not be empty")]
+    fn count_increases_empty_window() {
+        count_increases(TEST_DATA, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn count_increases_matches_naive(
+            input in prop::collection::vec(0..10_000i32, 0..200),
+            window in 1..20usize,
+        ) {
+            prop_assert_eq!(count_increases(&input, window), naive(&input, window));
+        }
+
+        #[test]
+        fn part_1_matches_naive(input in prop::collection::vec(0..10_000i32, 0..200)) {
+            prop_assert_eq!(part_1(&input), naive(&input, 1));
+        }
+
+        #[test]
+        fn part_2_matches_naive(input in prop::collection::vec(0..10_000i32, 0..200)) {
+            prop_assert_eq!(part_2(&input), naive(&input, 3));
+        }
+    }
+}
@@ -1,46 +1,294 @@
-use itertools::Itertools;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_common::Solution;
+use clap::{Args, Parser, Subcommand};
+use d01::{
+    Column, DepthStats, GeneratorOptions, PlotOptions, StreamCounter, Threshold, MIN_PLOT_SIZE,
+};
+use std::path::PathBuf;
 
-fn main() {
-    let input: Vec<i32> = BufReader::new(File::open("input.txt").unwrap())
-        .lines()
-        .map(|l| l.unwrap().parse().unwrap())
+#[derive(Parser)]
+#[command(about = "Day 1: Sonar Sweep", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Count increases while reading the input, without loading it at once
+    Stream(StreamArgs),
+    /// Summarize the depth profile of the input
+    Stats(StatsArgs),
+    /// Draw the depth profile of the input as an SVG chart
+    Plot(PlotArgs),
+    /// Find readings that spike and reverse right away
+    Spikes(SpikesArgs),
+    /// Generate a synthetic input and report its answers
+    Generate(GenerateArgs),
+    /// Count increases for every depth channel of a CSV file with a header
+    Csv(CsvArgs),
+}
+
+#[derive(Args)]
+struct StreamArgs {
+    /// window widths to count increases for
+    #[arg(short, long, default_values_t = [1, 3], value_parser = clap::value_parser!(u32).range(1..))]
+    window: Vec<u32>,
+
+    /// print running totals every this many lines
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    every: Option<u32>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "-")]
+    input: String,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    /// print the statistics as JSON
+    #[arg(long)]
+    json: bool,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Args)]
+struct PlotArgs {
+    /// also draw the sums of windows this wide
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    window: Option<u32>,
+
+    /// width of the chart in pixels
+    #[arg(long, default_value_t = PlotOptions::default().width, value_parser = clap::value_parser!(u32).range(i64::from(MIN_PLOT_SIZE)..))]
+    width: u32,
+
+    /// height of the chart in pixels
+    #[arg(long, default_value_t = PlotOptions::default().height, value_parser = clap::value_parser!(u32).range(i64::from(MIN_PLOT_SIZE)..))]
+    height: u32,
+
+    /// file to write the SVG to, stdout if not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("threshold").multiple(false))]
+struct SpikesArgs {
+    /// flag changes this many standard deviations from the mean change
+    #[arg(long, group = "threshold")]
+    z_score: Option<f64>,
+
+    /// flag changes this many median absolute deviations from the median
+    /// change [default: 5]
+    #[arg(long, group = "threshold")]
+    mad: Option<f64>,
+
+    /// print the input with the spikes smoothed out instead
+    #[arg(long)]
+    clean: bool,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// number of readings
+    #[arg(short, long, default_value_t = GeneratorOptions::default().length)]
+    length: usize,
+
+    /// depth of the first reading
+    #[arg(long, default_value_t = GeneratorOptions::default().start)]
+    start: i32,
+
+    /// average change from one reading to the next
+    #[arg(short, long, default_value_t = GeneratorOptions::default().trend, allow_negative_numbers = true)]
+    trend: f64,
+
+    /// readings vary by up to this much around the trend
+    #[arg(short, long, default_value_t = GeneratorOptions::default().noise)]
+    noise: f64,
+
+    /// chance of a reading being a spike, from 0 to 1
+    #[arg(long, default_value_t = GeneratorOptions::default().spike_rate)]
+    spike_rate: f64,
+
+    #[arg(long, default_value_t = GeneratorOptions::default().seed)]
+    seed: u64,
+
+    /// file to write the readings to, stdout if not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CsvArgs {
+    /// column to read, by header or 0-based index; all integer columns if
+    /// not given
+    #[arg(short, long)]
+    column: Vec<Column>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.csv")]
+    input: String,
+}
+
+/// Reads and parses the depths in `path`, exiting on errors.
+fn read_depths(path: &str) -> Vec<i32> {
+    let input = aoc_common::read_input(path).unwrap_or_else(|err| aoc_common::exit_with_error(err));
+    d01::Day01::parse(&input).unwrap_or_else(|err| {
+        aoc_common::exit_with_error(aoc_common::render_error(path, &input, &err))
+    })
+}
+
+fn totals(counters: &[StreamCounter]) -> String {
+    counters
+        .iter()
+        .map(|counter| format!("window {}: {}", counter.window(), counter.count()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn stream(args: &StreamArgs) {
+    let reader =
+        aoc_common::open_input(&args.input).unwrap_or_else(|err| aoc_common::exit_with_error(err));
+    let mut counters: Vec<_> = args
+        .window
+        .iter()
+        .map(|&w| StreamCounter::new(w as usize))
         .collect();
 
-    println!("part 1: {}", part_1(input.iter()));
-    println!("part 2: {}", part_2(input.iter()));
+    let result = d01::stream(reader, &mut counters, |number, counters| {
+        if args.every.is_some_and(|every| number % every as usize == 0) {
+            println!("line {}: {}", number, totals(counters));
+        }
+    });
+    if let Err(err) = result {
+        aoc_common::exit_with_error(format!("{}: {}", args.input, err));
+    }
+
+    for counter in &counters {
+        println!("window {}: {}", counter.window(), counter.count());
+    }
+}
+
+fn stats(args: &StatsArgs) {
+    let stats = DepthStats::new(&read_depths(&args.input))
+        .unwrap_or_else(|| aoc_common::exit_with_error(format!("{}: no depths", args.input)));
+
+    if args.json {
+        println!("{}", stats.to_json());
+    } else {
+        println!("{}", stats);
+    }
 }
 
-fn part_1<'a>(input: impl Iterator<Item = &'a i32>) -> usize {
-    input
-        .tuple_windows::<(&i32, &i32)>()
-        .filter(|&(lhs, rhs)| rhs > lhs)
-        .count()
+fn plot(args: &PlotArgs) {
+    let options = PlotOptions {
+        width: args.width,
+        height: args.height,
+        window: args.window.map(|window| window as usize),
+    };
+    let svg = d01::plot_svg(&read_depths(&args.input), &options);
+
+    match &args.output {
+        Some(path) => std::fs::write(path, svg).unwrap_or_else(|err| {
+            aoc_common::exit_with_error(format!("could not write {}: {}", path.display(), err))
+        }),
+        None => print!("{}", svg),
+    }
 }
 
-fn part_2<'a>(input: impl Iterator<Item = &'a i32>) -> usize {
-    input
-        .tuple_windows::<(&i32, &i32, &i32)>()
-        .map(|(i1, i2, i3)| i1 + i2 + i3)
-        .tuple_windows::<(i32, i32)>()
-        .filter(|&(lhs, rhs)| rhs > lhs)
-        .count()
+fn spikes(args: &SpikesArgs) {
+    let threshold = match (args.z_score, args.mad) {
+        (Some(limit), _) => Threshold::ZScore(limit),
+        (None, limit) => Threshold::Mad(limit.unwrap_or(5.)),
+    };
+    let depths = read_depths(&args.input);
+    let spikes = d01::find_spikes(&depths, threshold);
+    let cleaned = d01::remove_spikes(&depths, &spikes);
+
+    if args.clean {
+        cleaned.iter().for_each(|depth| println!("{}", depth));
+        return;
+    }
+
+    let indices: Vec<String> = spikes.iter().map(ToString::to_string).collect();
+    println!("spikes: {} ({})", spikes.len(), indices.join(", "));
+    println!(
+        "part 1: {} (cleaned: {})",
+        d01::part_1(&depths),
+        d01::part_1(&cleaned)
+    );
+    println!(
+        "part 2: {} (cleaned: {})",
+        d01::part_2(&depths),
+        d01::part_2(&cleaned)
+    );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn generate(args: &GenerateArgs) {
+    let depths = d01::generate(&GeneratorOptions {
+        length: args.length,
+        start: args.start,
+        trend: args.trend,
+        noise: args.noise,
+        spike_rate: args.spike_rate,
+        seed: args.seed,
+    });
+    let readings: String = depths.iter().map(|depth| format!("{}\n", depth)).collect();
+    let answers = format!(
+        "part 1: {}\npart 2: {}",
+        d01::part_1(&depths),
+        d01::part_2(&depths)
+    );
+
+    // keep stdout usable as input when the readings go there
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, readings).unwrap_or_else(|err| {
+                aoc_common::exit_with_error(format!("could not write {}: {}", path.display(), err))
+            });
+            println!("{}", answers);
+        }
+        None => {
+            print!("{}", readings);
+            eprintln!("{}", answers);
+        }
+    }
+}
 
-    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+fn csv(args: &CsvArgs) {
+    let reader =
+        aoc_common::open_input(&args.input).unwrap_or_else(|err| aoc_common::exit_with_error(err));
 
-    #[test]
-    fn part_1_works() {
-        assert_eq!(part_1(TEST_DATA.iter()), 7);
+    match d01::read_channels(reader, &args.column) {
+        Ok(channels) => print!("{}", d01::channel_table(&channels)),
+        Err(err) => aoc_common::exit_with_error(format!("{}: {}", args.input, err)),
     }
+}
+
+fn main() {
+    let cli = Cli::parse();
 
-    #[test]
-    fn part_2_works() {
-        assert_eq!(part_2(TEST_DATA.iter()), 5);
+    match &cli.command {
+        None => aoc_common::run_file::<d01::Day01>(&cli.input),
+        Some(Command::Stream(args)) => stream(args),
+        Some(Command::Stats(args)) => stats(args),
+        Some(Command::Plot(args)) => plot(args),
+        Some(Command::Spikes(args)) => spikes(args),
+        Some(Command::Generate(args)) => generate(args),
+        Some(Command::Csv(args)) => csv(args),
     }
 }
@@ -0,0 +1,95 @@
+use crate::count_increases;
+use rayon::prelude::*;
+
+/// Like [`count_increases`], but counts chunks of `chunk_size` comparisons
+/// on the rayon thread pool.
+///
+/// Every chunk also sees the `window` measurements following it, so the
+/// comparisons across chunk boundaries are counted exactly once.
+///
+/// # Panics
+///
+/// If `window` or `chunk_size` is 0.
+pub fn count_increases_parallel<T: Ord + Sync>(
+    input: &[T],
+    window: usize,
+    chunk_size: usize,
+) -> usize {
+    assert!(window > 0, "window must not be empty");
+    assert!(chunk_size > 0, "chunks must not be empty");
+
+    let comparisons = input.len().saturating_sub(window);
+    (0..comparisons.div_ceil(chunk_size))
+        .into_par_iter()
+        .map(|chunk| {
+            let start = chunk * chunk_size;
+            let end = (start + chunk_size).min(comparisons);
+            count_increases(&input[start..end + window], window)
+        })
+        .sum()
+}
+
+/// Chunk size of [`part_1_parallel`] and [`part_2_parallel`].
+pub const CHUNK_SIZE: usize = 1 << 16;
+
+/// [`part_1`](crate::part_1), counted in parallel.
+pub fn part_1_parallel<T: Ord + Sync>(input: &[T]) -> usize {
+    count_increases_parallel(input, 1, CHUNK_SIZE)
+}
+
+/// [`part_2`](crate::part_2), counted in parallel.
+pub fn part_2_parallel<T: Ord + Sync>(input: &[T]) -> usize {
+    count_increases_parallel(input, 3, CHUNK_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, part_1, part_2, GeneratorOptions};
+    use proptest::prelude::*;
+
+    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_small_chunks() {
+        for chunk_size in 1..12 {
+            assert_eq!(count_increases_parallel(TEST_DATA, 1, chunk_size), 7);
+            assert_eq!(count_increases_parallel(TEST_DATA, 3, chunk_size), 5);
+        }
+        assert_eq!(count_increases_parallel::<i32>(&[], 3, 2), 0);
+        assert_eq!(count_increases_parallel(&[1, 2], 3, 2), 0);
+    }
+
+    #[test]
+    fn test_huge_input() {
+        let input = generate(&GeneratorOptions {
+            length: 1_000_000,
+            noise: 20.,
+            spike_rate: 0.01,
+            ..GeneratorOptions::default()
+        });
+
+        assert_eq!(part_1_parallel(&input), part_1(&input));
+        assert_eq!(part_2_parallel(&input), part_2(&input));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunks must not be empty")]
+    fn test_empty_chunks() {
+        count_increases_parallel(TEST_DATA, 1, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sequential(
+            input in prop::collection::vec(0..1000i32, 0..300),
+            window in 1..10usize,
+            chunk_size in 1..50usize,
+        ) {
+            prop_assert_eq!(
+                count_increases_parallel(&input, window, chunk_size),
+                count_increases(&input, window)
+            );
+        }
+    }
+}
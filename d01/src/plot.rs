@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+const MARGIN: f64 = 40.;
+
+/// The smallest width and height [`plot_svg`] can draw in, leaving no room
+/// between the margins.
+pub const MIN_PLOT_SIZE: u32 = 2 * MARGIN as u32;
+const INCREASE: &str = "#2b7bba";
+const DECREASE: &str = "#e3702d";
+const UNCHANGED: &str = "#888888";
+const OVERLAY: &str = "#2a9d3f";
+
+/// How [`plot_svg`] draws the chart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlotOptions {
+    /// At least [`MIN_PLOT_SIZE`], like the height.
+    pub width: u32,
+    pub height: u32,
+    /// Overlays the sums of windows this wide, as used by
+    /// [`count_increases`](crate::count_increases).
+    pub window: Option<usize>,
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        PlotOptions {
+            width: 1200,
+            height: 600,
+            window: None,
+        }
+    }
+}
+
+/// Maps indices and depths into the drawing area. Depth grows downwards,
+/// like on the sonar.
+struct Scale {
+    x_step: f64,
+    y_min: f64,
+    y_step: f64,
+    bottom: f64,
+}
+
+impl Scale {
+    fn new(options: &PlotOptions, len: usize, min: i32, max: i32) -> Self {
+        let width = f64::from(options.width) - 2. * MARGIN;
+        let height = f64::from(options.height) - 2. * MARGIN;
+
+        Scale {
+            x_step: width / (len.max(2) - 1) as f64,
+            y_min: f64::from(min),
+            y_step: height
+                / Some(f64::from(max) - f64::from(min))
+                    .filter(|&span| span > 0.)
+                    .unwrap_or(1.),
+            bottom: MARGIN + height,
+        }
+    }
+
+    fn x(&self, index: usize) -> f64 {
+        MARGIN + index as f64 * self.x_step
+    }
+
+    fn y(&self, depth: f64) -> f64 {
+        MARGIN + (depth - self.y_min) * self.y_step
+    }
+}
+
+/// Draws `input` as an SVG line chart. Every step is coloured and shaded by
+/// whether the depth increases, decreases or stays the same. With a
+/// `window`, the window sums are drawn on top, divided by the width so they
+/// share the depth axis.
+pub fn plot_svg(input: &[i32], options: &PlotOptions) -> String {
+    let min = input.iter().copied().min().unwrap_or(0);
+    let max = input.iter().copied().max().unwrap_or(0);
+    let scale = Scale::new(options, input.len(), min, max);
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = options.width,
+        h = options.height
+    )
+    .unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+
+    // shading between each step and the bottom, then the steps themselves
+    let mut areas = [String::new(), String::new(), String::new()];
+    let mut lines = [String::new(), String::new(), String::new()];
+    for (index, pair) in input.windows(2).enumerate() {
+        let kind = match pair[1].cmp(&pair[0]) {
+            Ordering::Greater => 0,
+            Ordering::Less => 1,
+            Ordering::Equal => 2,
+        };
+        let (x0, x1) = (scale.x(index), scale.x(index + 1));
+        let (y0, y1) = (scale.y(f64::from(pair[0])), scale.y(f64::from(pair[1])));
+
+        write!(
+            areas[kind],
+            "M{:.1},{:.1}L{:.1},{:.1}L{:.1},{:.1}L{:.1},{:.1}Z",
+            x0, y0, x1, y1, x1, scale.bottom, x0, scale.bottom
+        )
+        .unwrap();
+        write!(lines[kind], "M{:.1},{:.1}L{:.1},{:.1}", x0, y0, x1, y1).unwrap();
+    }
+    for ((class, color), (area, line)) in [
+        ("increase", INCREASE),
+        ("decrease", DECREASE),
+        ("unchanged", UNCHANGED),
+    ]
+    .iter()
+    .zip(areas.iter().zip(lines.iter()))
+    {
+        if line.is_empty() {
+            continue;
+        }
+        writeln!(
+            svg,
+            r#"<path class="{}-area" d="{}" fill="{}" fill-opacity="0.25"/>"#,
+            class, area, color
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<path class="{}" d="{}" fill="none" stroke="{}" stroke-width="1"/>"#,
+            class, line, color
+        )
+        .unwrap();
+    }
+
+    if let Some(window) = options.window.filter(|&window| window > 0) {
+        let points: Vec<String> = input
+            .windows(window)
+            .enumerate()
+            .map(|(start, values)| {
+                let sum: i64 = values.iter().map(|&v| i64::from(v)).sum();
+                // centered on the window it sums up
+                let x = scale.x(start) + (window - 1) as f64 * scale.x_step / 2.;
+                format!("{:.1},{:.1}", x, scale.y(sum as f64 / window as f64))
+            })
+            .collect();
+        if !points.is_empty() {
+            writeln!(
+                svg,
+                r#"<polyline class="window" points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+                points.join(" "),
+                OVERLAY
+            )
+            .unwrap();
+        }
+    }
+
+    // axes, labelled with the shallowest and deepest measurement
+    writeln!(
+        svg,
+        r#"<path class="axes" d="M{m},{m}L{m},{b}L{r},{b}" fill="none" stroke="black"/>"#,
+        m = MARGIN,
+        b = scale.bottom,
+        r = f64::from(options.width) - MARGIN
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="12" text-anchor="end">{}</text>"#,
+        MARGIN - 4.,
+        MARGIN + 4.,
+        min
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="12" text-anchor="end">{}</text>"#,
+        MARGIN - 4.,
+        scale.bottom + 4.,
+        max
+    )
+    .unwrap();
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn path<'a>(svg: &'a str, class: &str) -> Option<&'a str> {
+        let start = svg.find(&format!(r#"class="{}" d=""#, class))?;
+        let d = &svg[start + class.len() + 12..];
+        Some(&d[..d.find('"').unwrap()])
+    }
+
+    #[test]
+    fn test_plot() {
+        let svg = plot_svg(TEST_DATA, &PlotOptions::default());
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(path(&svg, "increase").unwrap().matches('M').count(), 7);
+        assert_eq!(path(&svg, "decrease").unwrap().matches('M').count(), 2);
+        assert_eq!(path(&svg, "unchanged"), None);
+        assert!(!svg.contains("polyline"));
+
+        // the shallowest point at the top, the deepest at the bottom
+        assert!(path(&svg, "increase").unwrap().starts_with("M40.0,40.0L"));
+        assert!(path(&svg, "increase").unwrap().contains("L911.1,560.0"));
+    }
+
+    #[test]
+    fn test_plot_window() {
+        let options = PlotOptions {
+            window: Some(3),
+            ..PlotOptions::default()
+        };
+        let svg = plot_svg(TEST_DATA, &options);
+
+        let start = svg.find("points=\"").unwrap() + 8;
+        let points = &svg[start..start + svg[start..].find('"').unwrap()];
+        assert_eq!(points.split(' ').count(), 8);
+        // 607 / 3 on the depth axis, centered on the second measurement
+        assert!(points.starts_with("164.4,"));
+    }
+
+    #[test]
+    fn test_plot_tiny_inputs() {
+        for input in [&[][..], &[5], &[5, 5]] {
+            let svg = plot_svg(input, &PlotOptions::default());
+            assert!(svg.ends_with("</svg>\n"));
+        }
+        assert!(path(&plot_svg(&[5, 5], &PlotOptions::default()), "unchanged").is_some());
+    }
+
+    #[test]
+    fn test_plot_extreme_depths() {
+        let svg = plot_svg(&[i32::MIN, i32::MAX, 0], &PlotOptions::default());
+        assert_eq!(path(&svg, "increase"), Some("M40.0,40.0L600.0,560.0"));
+        assert_eq!(path(&svg, "decrease"), Some("M600.0,560.0L1160.0,300.0"));
+    }
+}
@@ -0,0 +1,253 @@
+use serde_json::json;
+use std::fmt::{self, Display};
+
+/// Indices of the first and last measurement of a strictly increasing or
+/// decreasing run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    /// Number of measurements in the run.
+    pub fn length(&self) -> usize {
+        self.end - self.start + 1
+    }
+}
+
+/// The change from the measurement before `index` to the one at `index`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jump {
+    pub index: usize,
+    pub change: i64,
+}
+
+/// Summary of a sonar sweep.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepthStats {
+    pub measurements: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+    /// First of the longest runs, `None` without any increase.
+    pub longest_increasing: Option<Run>,
+    /// First of the longest runs, `None` without any decrease.
+    pub longest_decreasing: Option<Run>,
+    /// First of the largest changes in either direction, `None` with fewer
+    /// than two measurements.
+    pub largest_jump: Option<Jump>,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+}
+
+impl DepthStats {
+    /// Collects the statistics of `input`, `None` if it is empty.
+    pub fn new(input: &[i32]) -> Option<Self> {
+        let mut stats = DepthStats {
+            measurements: input.len(),
+            increases: 0,
+            decreases: 0,
+            unchanged: 0,
+            longest_increasing: None,
+            longest_decreasing: None,
+            largest_jump: None,
+            min: *input.iter().min()?,
+            max: *input.iter().max()?,
+            mean: input.iter().map(|&d| f64::from(d)).sum::<f64>() / input.len() as f64,
+        };
+
+        let mut increasing = Run { start: 0, end: 0 };
+        let mut decreasing = Run { start: 0, end: 0 };
+        for (index, pair) in input.windows(2).enumerate().map(|(i, p)| (i + 1, p)) {
+            let change = i64::from(pair[1]) - i64::from(pair[0]);
+
+            if change > 0 {
+                stats.increases += 1;
+            } else if change < 0 {
+                stats.decreases += 1;
+            } else {
+                stats.unchanged += 1;
+            }
+
+            if change > 0 && increasing.end == index - 1 {
+                increasing.end = index;
+            } else {
+                increasing = Run {
+                    start: index,
+                    end: index,
+                };
+            }
+            if change < 0 && decreasing.end == index - 1 {
+                decreasing.end = index;
+            } else {
+                decreasing = Run {
+                    start: index,
+                    end: index,
+                };
+            }
+            longest(&mut stats.longest_increasing, increasing);
+            longest(&mut stats.longest_decreasing, decreasing);
+
+            if stats
+                .largest_jump
+                .is_none_or(|jump| change.abs() > jump.change.abs())
+            {
+                stats.largest_jump = Some(Jump { index, change });
+            }
+        }
+
+        Some(stats)
+    }
+
+    /// The statistics as a JSON object.
+    pub fn to_json(&self) -> String {
+        let run = |run: Option<Run>| {
+            run.map(|run| json!({"start": run.start, "end": run.end, "length": run.length()}))
+        };
+
+        json!({
+            "measurements": self.measurements,
+            "increases": self.increases,
+            "decreases": self.decreases,
+            "unchanged": self.unchanged,
+            "longest_increasing": run(self.longest_increasing),
+            "longest_decreasing": run(self.longest_decreasing),
+            "largest_jump": self.largest_jump.map(|jump| json!({
+                "index": jump.index,
+                "change": jump.change,
+            })),
+            "min": self.min,
+            "max": self.max,
+            "mean": self.mean,
+        })
+        .to_string()
+    }
+}
+
+/// Keeps `run` as the longest one if it is longer than the current one and
+/// actually spans a change.
+fn longest(current: &mut Option<Run>, run: Run) {
+    if run.length() > 1 && current.is_none_or(|current| run.length() > current.length()) {
+        *current = Some(run);
+    }
+}
+
+impl Display for DepthStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let run = |run: Option<Run>| match run {
+            Some(run) => format!(
+                "{} measurements, index {} to {}",
+                run.length(),
+                run.start,
+                run.end
+            ),
+            None => "-".into(),
+        };
+
+        writeln!(f, "measurements:       {}", self.measurements)?;
+        writeln!(f, "increases:          {}", self.increases)?;
+        writeln!(f, "decreases:          {}", self.decreases)?;
+        writeln!(f, "unchanged:          {}", self.unchanged)?;
+        writeln!(f, "longest increasing: {}", run(self.longest_increasing))?;
+        writeln!(f, "longest decreasing: {}", run(self.longest_decreasing))?;
+        match self.largest_jump {
+            Some(jump) => writeln!(
+                f,
+                "largest jump:       {:+} at index {}",
+                jump.change, jump.index
+            )?,
+            None => writeln!(f, "largest jump:       -")?,
+        }
+        writeln!(f, "min:                {}", self.min)?;
+        writeln!(f, "max:                {}", self.max)?;
+        write!(f, "mean:               {:.2}", self.mean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_stats() {
+        let stats = DepthStats::new(TEST_DATA).unwrap();
+
+        assert_eq!(
+            stats,
+            DepthStats {
+                measurements: 10,
+                increases: 7,
+                decreases: 2,
+                unchanged: 0,
+                longest_increasing: Some(Run { start: 0, end: 3 }),
+                longest_decreasing: Some(Run { start: 3, end: 4 }),
+                largest_jump: Some(Jump {
+                    index: 6,
+                    change: 33
+                }),
+                min: 199,
+                max: 269,
+                mean: 225.6,
+            }
+        );
+        assert_eq!(stats.increases, crate::part_1(TEST_DATA));
+    }
+
+    #[test]
+    fn test_stats_edge_cases() {
+        assert_eq!(DepthStats::new(&[]), None);
+
+        let stats = DepthStats::new(&[5]).unwrap();
+        assert_eq!(stats.longest_increasing, None);
+        assert_eq!(stats.largest_jump, None);
+
+        let stats = DepthStats::new(&[5, 5, 3, 2, 1, 1]).unwrap();
+        assert_eq!(stats.unchanged, 2);
+        assert_eq!(stats.longest_increasing, None);
+        assert_eq!(stats.longest_decreasing, Some(Run { start: 1, end: 4 }));
+        assert_eq!(
+            stats.largest_jump,
+            Some(Jump {
+                index: 2,
+                change: -2
+            })
+        );
+
+        let stats = DepthStats::new(&[i32::MIN, i32::MAX, i32::MIN]).unwrap();
+        assert_eq!((stats.increases, stats.decreases), (1, 1));
+        assert_eq!(
+            stats.largest_jump,
+            Some(Jump {
+                index: 1,
+                change: 4294967295
+            })
+        );
+        assert!(stats.to_json().contains(r#""change":4294967295"#));
+    }
+
+    #[test]
+    fn test_render() {
+        let stats = DepthStats::new(TEST_DATA).unwrap();
+
+        assert_eq!(
+            stats.to_string().lines().nth(4),
+            Some("longest increasing: 4 measurements, index 0 to 3")
+        );
+        assert_eq!(
+            stats.to_string().lines().nth(6),
+            Some("largest jump:       +33 at index 6")
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&stats.to_json()).unwrap();
+        assert_eq!(
+            json["longest_increasing"],
+            json!({"start": 0, "end": 3, "length": 4})
+        );
+        assert_eq!(json["largest_jump"]["change"], 33);
+        assert_eq!(json["mean"], 225.6);
+    }
+}
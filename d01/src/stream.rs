@@ -0,0 +1,131 @@
+use aoc_common::{InputLine, Result};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+/// Counts increases of `window`-measurement sums like
+/// [`count_increases`](crate::count_increases), one measurement at a time,
+/// keeping only the last `window` measurements.
+#[derive(Debug)]
+pub struct StreamCounter {
+    window: usize,
+    last: VecDeque<i32>,
+    count: usize,
+}
+
+impl StreamCounter {
+    /// # Panics
+    ///
+    /// If `window` is 0.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window must not be empty");
+
+        StreamCounter {
+            window,
+            last: VecDeque::with_capacity(window),
+            count: 0,
+        }
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Increases counted so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Adds the next measurement and returns the increases counted so far.
+    pub fn push(&mut self, depth: i32) -> usize {
+        if self.last.len() == self.window {
+            let first = self.last.pop_front().unwrap();
+            if depth > first {
+                self.count += 1;
+            }
+        }
+        self.last.push_back(depth);
+        self.count
+    }
+}
+
+/// Reads one depth per line from `reader` and feeds them to all `counters`.
+/// Empty lines are skipped, like in the regular input. After every line
+/// `progress` is called with the line number and the counters.
+pub fn stream(
+    mut reader: impl BufRead,
+    counters: &mut [StreamCounter],
+    mut progress: impl FnMut(usize, &[StreamCounter]),
+) -> Result<()> {
+    let mut buf = String::new();
+    let mut number = 0;
+
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let line = InputLine::new(number, buf.trim_end_matches(['\n', '\r']));
+        if line.text.is_empty() {
+            continue;
+        }
+        let depth = line.parse(line.text)?;
+
+        for counter in counters.iter_mut() {
+            counter.push(depth);
+        }
+        progress(number, counters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Error, ParseError};
+
+    const TEST_DATA: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn test_stream() {
+        let mut counters = [StreamCounter::new(1), StreamCounter::new(3)];
+        let mut totals = Vec::new();
+
+        stream(TEST_DATA.as_bytes(), &mut counters, |number, counters| {
+            totals.push((number, counters[0].count(), counters[1].count()))
+        })
+        .unwrap();
+
+        assert_eq!(counters[0].count(), 7);
+        assert_eq!(counters[1].count(), 5);
+        assert_eq!(totals.len(), 10);
+        assert_eq!(totals[3], (4, 3, 1));
+    }
+
+    #[test]
+    fn test_stream_matches_count_increases() {
+        let depths: Vec<i32> = TEST_DATA.lines().map(|l| l.parse().unwrap()).collect();
+
+        for window in 1..12 {
+            let mut counter = StreamCounter::new(window);
+            depths.iter().for_each(|&depth| {
+                counter.push(depth);
+            });
+            assert_eq!(counter.count(), crate::count_increases(&depths, window));
+        }
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let mut counters = [StreamCounter::new(1)];
+        let err = stream("199\n\n2x0\n".as_bytes(), &mut counters, |_, _| {}).unwrap_err();
+
+        match err {
+            Error::Parse(err) => assert_eq!(
+                err,
+                ParseError::new(3, 1, "2x0", "invalid digit found in string")
+            ),
+            _ => panic!("expected a parse error, got {:?}", err),
+        }
+    }
+}
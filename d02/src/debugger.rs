@@ -0,0 +1,569 @@
+use crate::{Command, Position};
+use aoc_common::Part;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variable {
+    Horizontal,
+    Depth,
+    Aim,
+}
+
+impl Variable {
+    pub const ALL: [Variable; 3] = [Variable::Horizontal, Variable::Depth, Variable::Aim];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Variable::Horizontal => "horizontal",
+            Variable::Depth => "depth",
+            Variable::Aim => "aim",
+        }
+    }
+
+    /// The value of this variable at `position`.
+    pub fn of(self, position: &Position) -> i64 {
+        match self {
+            Variable::Horizontal => position.horizontal,
+            Variable::Depth => position.depth,
+            Variable::Aim => position.aim,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+const COMPARISONS: [(&str, Comparison); 6] = [
+    ("<=", Comparison::LessOrEqual),
+    (">=", Comparison::GreaterOrEqual),
+    ("==", Comparison::Equal),
+    ("!=", Comparison::NotEqual),
+    ("<", Comparison::Less),
+    (">", Comparison::Greater),
+];
+
+/// A breakpoint condition like `depth > 1000`, which holds under either
+/// part, or `2 aim < 0`, which only holds under the rules of part 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub part: Option<Part>,
+    pub variable: Variable,
+    pub comparison: Comparison,
+    pub value: i64,
+}
+
+/// Why a breakpoint condition can't be parsed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConditionError {
+    #[error("expected a condition like `depth > 1000`")]
+    MissingComparison,
+    #[error("unknown variable `{0}`, expected horizontal, depth or aim")]
+    UnknownVariable(String),
+    #[error("invalid number `{0}`: {1}")]
+    InvalidNumber(String, ParseIntError),
+}
+
+impl FromStr for Condition {
+    type Err = ConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (part, s) = match s.trim().split_once(char::is_whitespace) {
+            Some(("1", rest)) => (Some(Part::One), rest),
+            Some(("2", rest)) => (Some(Part::Two), rest),
+            _ => (None, s),
+        };
+
+        let (start, symbol, comparison) = COMPARISONS
+            .iter()
+            .filter_map(|&(symbol, comparison)| Some((s.find(symbol)?, symbol, comparison)))
+            .min_by_key(|&(start, symbol, _)| (start, usize::MAX - symbol.len()))
+            .ok_or(ConditionError::MissingComparison)?;
+        let (variable, value) = (s[..start].trim(), s[start + symbol.len()..].trim());
+
+        let variable = Variable::ALL
+            .into_iter()
+            .find(|v| v.name() == variable)
+            .ok_or_else(|| ConditionError::UnknownVariable(variable.into()))?;
+        let value = value
+            .parse()
+            .map_err(|err| ConditionError::InvalidNumber(value.into(), err))?;
+
+        Ok(Condition {
+            part,
+            variable,
+            comparison,
+            value,
+        })
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(part) = self.part {
+            write!(f, "{} ", part)?;
+        }
+        let symbol = COMPARISONS
+            .iter()
+            .find(|(_, comparison)| *comparison == self.comparison)
+            .map_or("?", |(symbol, _)| symbol);
+        write!(f, "{} {} {}", self.variable.name(), symbol, self.value)
+    }
+}
+
+impl Condition {
+    /// Whether the condition holds for `position` under the rules of `part`.
+    pub fn holds(&self, position: &Position, part: Part) -> bool {
+        if self.part.is_some_and(|only| only != part) {
+            return false;
+        }
+
+        let actual = self.variable.of(position);
+        match self.comparison {
+            Comparison::Less => actual < self.value,
+            Comparison::LessOrEqual => actual <= self.value,
+            Comparison::Greater => actual > self.value,
+            Comparison::GreaterOrEqual => actual >= self.value,
+            Comparison::Equal => actual == self.value,
+            Comparison::NotEqual => actual != self.value,
+        }
+    }
+}
+
+fn index(part: Part) -> usize {
+    usize::from(part.number() - 1)
+}
+
+/// Steps through a list of commands, following them under the rules of
+/// both parts at once.
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    commands: Vec<Command>,
+    /// The positions under the rules of each part, up to where they
+    /// overflow.
+    positions: [Vec<Position>; 2],
+    step: usize,
+    breakpoints: Vec<Condition>,
+}
+
+impl Debugger {
+    /// Starts at the surface, before the first command.
+    pub fn new(commands: Vec<Command>) -> Self {
+        let positions = Part::ALL.map(|part| {
+            let mut positions = vec![Position::default()];
+            for command in &commands {
+                match positions[positions.len() - 1].step(command, part) {
+                    Some(next) => positions.push(next),
+                    None => break,
+                }
+            }
+            positions
+        });
+
+        Debugger {
+            commands,
+            positions,
+            step: 0,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// The number of commands followed so far.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// The current position under the rules of `part`, `None` once it has
+    /// overflowed.
+    pub fn position(&self, part: Part) -> Option<Position> {
+        self.positions[index(part)].get(self.step).copied()
+    }
+
+    /// Goes to the position after `step` commands, or the last one.
+    pub fn go_to(&mut self, step: usize) {
+        self.step = step.min(self.commands.len());
+    }
+
+    pub fn forward(&mut self, steps: usize) {
+        self.go_to(self.step.saturating_add(steps));
+    }
+
+    pub fn back(&mut self, steps: usize) {
+        self.go_to(self.step.saturating_sub(steps));
+    }
+
+    pub fn breakpoints(&self) -> &[Condition] {
+        &self.breakpoints
+    }
+
+    pub fn add_breakpoint(&mut self, condition: Condition) {
+        self.breakpoints.push(condition);
+    }
+
+    /// Removes the breakpoint at the 0-based `index`, if there is one.
+    pub fn remove_breakpoint(&mut self, index: usize) -> Option<Condition> {
+        (index < self.breakpoints.len()).then(|| self.breakpoints.remove(index))
+    }
+
+    /// Steps forward until a breakpoint holds, returning its index and the
+    /// part it holds under, or up to the last position if none does.
+    pub fn resume(&mut self) -> Option<(usize, Part)> {
+        while self.step < self.commands.len() {
+            self.step += 1;
+            for part in Part::ALL {
+                if let Some(position) = self.position(part) {
+                    let hit = self
+                        .breakpoints
+                        .iter()
+                        .position(|b| b.holds(&position, part));
+                    if let Some(hit) = hit {
+                        return Some((hit, part));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The current step and the positions under both parts side by side.
+    pub fn state(&self) -> String {
+        let mut state = format!("step {} of {}", self.step, self.commands.len());
+        if let Some(command) = self.step.checked_sub(1).map(|i| self.commands[i]) {
+            state += &format!(", after `{}`", command);
+        }
+
+        let cells = Variable::ALL.map(|variable| {
+            Part::ALL.map(|part| {
+                self.position(part)
+                    .map_or("overflow".into(), |p| variable.of(&p).to_string())
+            })
+        });
+        let width = cells
+            .iter()
+            .flatten()
+            .map(String::len)
+            .chain(Some("part 1".len()))
+            .max()
+            .unwrap_or_default();
+
+        state += &format!(
+            "\n{:<10}  {:>w$}  {:>w$}",
+            "",
+            "part 1",
+            "part 2",
+            w = width
+        );
+        for (variable, [one, two]) in Variable::ALL.iter().zip(&cells) {
+            state += &format!(
+                "\n{:<10}  {:>w$}  {:>w$}",
+                variable.name(),
+                one,
+                two,
+                w = width
+            );
+        }
+        state
+    }
+}
+
+const HELP: &str = "\
+step [n]          follow the next n commands, 1 if not given
+back [n]          go back n commands, 1 if not given
+goto <step>       go to the position after this many commands
+continue          step forward until a breakpoint holds
+break <cond>      stop where a condition like `depth > 1000` holds under
+                  either part, or `2 aim < 0` only under part 2
+breakpoints       list the breakpoints
+delete <n>        delete breakpoint n
+print             show the current positions
+quit";
+
+/// Reads debugger commands from `input` until it ends or the user quits,
+/// writing the results to `output`.
+pub fn repl(
+    debugger: &mut Debugger,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    writeln!(output, "{}", debugger.state())?;
+    let mut lines = input.lines();
+
+    loop {
+        write!(output, "(d02) ")?;
+        output.flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return writeln!(output),
+        };
+        let (verb, argument) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        let argument = argument.trim();
+        let count = || match argument {
+            "" => Ok(1),
+            _ => argument.parse::<usize>().map_err(|err| err.to_string()),
+        };
+
+        let reply = match verb {
+            "" => continue,
+            "step" | "s" => count().map(|n| {
+                debugger.forward(n);
+                debugger.state()
+            }),
+            "back" | "b" => count().map(|n| {
+                debugger.back(n);
+                debugger.state()
+            }),
+            "goto" | "g" => argument
+                .parse()
+                .map(|step| {
+                    debugger.go_to(step);
+                    debugger.state()
+                })
+                .map_err(|err| err.to_string()),
+            "continue" | "c" => Ok(match debugger.resume() {
+                Some((hit, part)) => format!(
+                    "breakpoint {} ({}) under part {}\n{}",
+                    hit + 1,
+                    debugger.breakpoints()[hit],
+                    part,
+                    debugger.state()
+                ),
+                None => format!("no breakpoint hit\n{}", debugger.state()),
+            }),
+            "break" => argument
+                .parse()
+                .map(|condition| {
+                    debugger.add_breakpoint(condition);
+                    format!("breakpoint {}: {}", debugger.breakpoints().len(), condition)
+                })
+                .map_err(|err: ConditionError| err.to_string()),
+            "breakpoints" => Ok(match debugger.breakpoints() {
+                [] => "no breakpoints".into(),
+                breakpoints => breakpoints
+                    .iter()
+                    .enumerate()
+                    .map(|(i, condition)| format!("breakpoint {}: {}", i + 1, condition))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            }),
+            "delete" | "d" => argument
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| debugger.remove_breakpoint(n.checked_sub(1)?))
+                .map(|condition| format!("deleted breakpoint {}", condition))
+                .ok_or_else(|| format!("no breakpoint `{}`", argument)),
+            "print" | "p" => Ok(debugger.state()),
+            "help" | "h" => Ok(HELP.into()),
+            "quit" | "q" => return Ok(()),
+            _ => Err(format!("unknown command `{}`, try `help`", verb)),
+        };
+
+        match reply {
+            Ok(reply) => writeln!(output, "{}", reply)?,
+            Err(err) => writeln!(output, "error: {}", err)?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[Command] = &[
+        Command::Forward(5),
+        Command::Down(5),
+        Command::Forward(8),
+        Command::Up(3),
+        Command::Down(8),
+        Command::Forward(2),
+    ];
+
+    fn session(debugger: &mut Debugger, input: &str) -> String {
+        let mut output = Vec::new();
+        repl(debugger, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_conditions() {
+        let deep: Condition = "depth > 1000".parse().unwrap();
+        assert_eq!(
+            deep,
+            Condition {
+                part: None,
+                variable: Variable::Depth,
+                comparison: Comparison::Greater,
+                value: 1000
+            }
+        );
+        assert_eq!(deep.to_string(), "depth > 1000");
+
+        let up: Condition = "2 aim<0".parse().unwrap();
+        assert_eq!(up.to_string(), "2 aim < 0");
+        assert_eq!(
+            "horizontal>=-5".parse::<Condition>().unwrap().comparison,
+            Comparison::GreaterOrEqual
+        );
+
+        let position = Position {
+            horizontal: 1,
+            depth: 1001,
+            aim: -1,
+        };
+        assert!(deep.holds(&position, Part::One));
+        assert!(up.holds(&position, Part::Two));
+        assert!(!up.holds(&position, Part::One));
+        assert!(!"depth != 1001"
+            .parse::<Condition>()
+            .unwrap()
+            .holds(&position, Part::One));
+    }
+
+    #[test]
+    fn test_condition_errors() {
+        assert_eq!(
+            "depth".parse::<Condition>(),
+            Err(ConditionError::MissingComparison)
+        );
+        assert_eq!(
+            "speed > 5".parse::<Condition>(),
+            Err(ConditionError::UnknownVariable("speed".into()))
+        );
+        assert!(matches!(
+            "depth > deep".parse::<Condition>(),
+            Err(ConditionError::InvalidNumber(number, _)) if number == "deep"
+        ));
+        assert!(matches!(
+            "3 depth > 1".parse::<Condition>(),
+            Err(ConditionError::UnknownVariable(variable)) if variable == "3 depth"
+        ));
+    }
+
+    #[test]
+    fn test_stepping() {
+        let mut debugger = Debugger::new(TEST_DATA.to_vec());
+        assert_eq!(debugger.position(Part::Two), Some(Position::default()));
+
+        debugger.forward(3);
+        assert_eq!(debugger.position(Part::One).unwrap().depth, 5);
+        assert_eq!(debugger.position(Part::Two).unwrap().depth, 40);
+        debugger.back(2);
+        assert_eq!(debugger.step(), 1);
+        debugger.back(5);
+        assert_eq!(debugger.step(), 0);
+        debugger.forward(usize::MAX);
+        assert_eq!(debugger.step(), 6);
+        assert_eq!(debugger.position(Part::Two).unwrap().depth, 60);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = Debugger::new(TEST_DATA.to_vec());
+        debugger.add_breakpoint("2 depth > 0".parse().unwrap());
+        debugger.add_breakpoint("aim > 4".parse().unwrap());
+
+        assert_eq!(debugger.resume(), Some((1, Part::Two)));
+        assert_eq!(debugger.step(), 2);
+        assert_eq!(debugger.resume(), Some((0, Part::Two)));
+        assert_eq!(debugger.step(), 3);
+
+        assert_eq!(
+            debugger.remove_breakpoint(0),
+            Some("2 depth > 0".parse().unwrap())
+        );
+        assert_eq!(debugger.remove_breakpoint(1), None);
+        assert_eq!(debugger.resume(), Some((0, Part::Two)));
+        assert_eq!(debugger.step(), 5);
+        assert_eq!(debugger.resume(), Some((0, Part::Two)));
+        assert_eq!(debugger.resume(), None);
+        assert_eq!(debugger.step(), 6);
+    }
+
+    #[test]
+    fn test_state() {
+        let mut debugger = Debugger::new(TEST_DATA.to_vec());
+        debugger.go_to(3);
+
+        assert_eq!(
+            debugger.state(),
+            "step 3 of 6, after `forward 8`\n\
+             \x20           part 1  part 2\n\
+             horizontal      13      13\n\
+             depth            5      40\n\
+             aim              0       5"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut debugger = Debugger::new(vec![
+            Command::Down(i32::MAX),
+            Command::Down(i32::MAX),
+            Command::Forward(i32::MAX),
+            Command::Forward(i32::MAX),
+            Command::Up(1),
+        ]);
+        debugger.go_to(4);
+
+        assert_eq!(debugger.position(Part::Two), None);
+        assert_eq!(
+            debugger.position(Part::One).unwrap().horizontal,
+            2 * i64::from(i32::MAX)
+        );
+        assert!(debugger
+            .state()
+            .contains("\ndepth       4294967294    overflow"));
+
+        debugger.add_breakpoint("aim > 0".parse().unwrap());
+        debugger.go_to(0);
+        assert_eq!(debugger.resume(), Some((0, Part::Two)));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut debugger = Debugger::new(TEST_DATA.to_vec());
+        let output = session(
+            &mut debugger,
+            "break 2 depth > 10\nbreak speed > 1\nc\nback 2\nstep x\nbreakpoints\n\
+             delete 1\ndelete 1\nfly\n\nc\nq\nprint\n",
+        );
+        let replies: Vec<&str> = output
+            .split("(d02) ")
+            .skip(1)
+            .map(|reply| reply.lines().next().unwrap_or_default())
+            .collect();
+
+        assert_eq!(
+            replies,
+            vec![
+                "breakpoint 1: 2 depth > 10",
+                "error: unknown variable `speed`, expected horizontal, depth or aim",
+                "breakpoint 1 (2 depth > 10) under part 2",
+                "step 1 of 6, after `forward 5`",
+                "error: invalid digit found in string",
+                "breakpoint 1: 2 depth > 10",
+                "deleted breakpoint 2 depth > 10",
+                "error: no breakpoint `1`",
+                "error: unknown command `fly`, try `help`",
+                "",
+                "no breakpoint hit",
+                "",
+            ]
+        );
+        assert_eq!(debugger.step(), 6);
+        assert!(output.starts_with("step 0 of 6\n"));
+    }
+}
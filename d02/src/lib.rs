@@ -0,0 +1,274 @@
+use aoc_common::{InputLine, ParseError, Part, Result, Solution};
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error;
+
+mod debugger;
+mod plan;
+mod script;
+mod trajectory;
+
+pub use debugger::{repl, Comparison, Condition, ConditionError, Debugger, Variable};
+pub use plan::plan;
+pub use script::{expand, Script, MAX_COMMANDS};
+pub use trajectory::{OverflowError, Position, Trajectory};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl Display for Command {
+    /// Formats the command as it appears in the input.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(n) => write!(f, "forward {}", n),
+            Command::Down(n) => write!(f, "down {}", n),
+            Command::Up(n) => write!(f, "up {}", n),
+        }
+    }
+}
+
+/// Why a line isn't a valid [`Command`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    #[error("unknown command `{0}`")]
+    UnknownVerb(String),
+    #[error("missing the number after the command")]
+    MissingArgument,
+    #[error("invalid number `{0}`: {1}")]
+    InvalidArgument(String, ParseIntError),
+    #[error("unexpected `{0}` after the number")]
+    TrailingGarbage(String),
+}
+
+/// The verb, the argument and whatever follows them, all slices of `s`.
+fn split_command(s: &str) -> (&str, Option<&str>, &str) {
+    let s = s.trim();
+    let (verb, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    let rest = rest.trim_start();
+    let (argument, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+
+    let argument = Some(argument).filter(|argument| !argument.is_empty());
+    (verb, argument, rest.trim())
+}
+
+impl FromStr for Command {
+    type Err = CommandError;
+
+    /// Parses commands like `forward 5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (verb, argument, rest) = split_command(s);
+
+        let command: fn(i32) -> Command = match verb {
+            "forward" => Command::Forward,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            _ => return Err(CommandError::UnknownVerb(verb.into())),
+        };
+        let argument = argument.ok_or(CommandError::MissingArgument)?;
+        let number = argument
+            .parse()
+            .map_err(|err| CommandError::InvalidArgument(argument.into(), err))?;
+        if !rest.is_empty() {
+            return Err(CommandError::TrailingGarbage(rest.into()));
+        }
+
+        Ok(command(number))
+    }
+}
+
+/// Day 2: Dive!
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_common::collect_parsed(
+            aoc_common::numbered_lines(input).map(|line| parse_command(&line)),
+        )
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part_1(input)?)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(part_2(input)?)
+    }
+}
+
+/// Parses a command, pointing errors at the offending part of the line.
+fn parse_command(line: &InputLine) -> Result<Command, ParseError> {
+    line.text.parse().map_err(|err| {
+        let (verb, argument, rest) = split_command(line.text);
+        match err {
+            CommandError::UnknownVerb(_) => line.error(verb, "unknown command"),
+            CommandError::MissingArgument => {
+                line.error(line.text, "expected a command and a number")
+            }
+            CommandError::InvalidArgument(_, err) => line.error(argument.unwrap_or_default(), err),
+            CommandError::TrailingGarbage(_) => {
+                line.error(rest, "unexpected text after the number")
+            }
+        }
+    })
+}
+
+/// Follows the commands with `down`/`up` changing the depth directly and
+/// returns horizontal position times depth.
+pub fn part_1<'a>(input: impl IntoIterator<Item = &'a Command>) -> Result<i64, OverflowError> {
+    Position::follow(input, Part::One)?.product()
+}
+
+/// Follows the commands with `down`/`up` changing the aim, which `forward`
+/// turns into depth, and returns horizontal position times depth.
+pub fn part_2<'a>(input: impl IntoIterator<Item = &'a Command>) -> Result<i64, OverflowError> {
+    Position::follow(input, Part::Two)?.product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[&Command] = &[
+        &Command::Forward(5),
+        &Command::Down(5),
+        &Command::Forward(8),
+        &Command::Up(3),
+        &Command::Down(8),
+        &Command::Forward(2),
+    ];
+
+    #[test]
+    fn part_1_works() {
+        assert_eq!(part_1(TEST_DATA.iter().cloned()), Ok(150));
+    }
+
+    #[test]
+    fn part_2_works() {
+        assert_eq!(part_2(TEST_DATA.iter().cloned()), Ok(900));
+    }
+
+    #[test]
+    fn no_overflow_past_i32() {
+        // both used to wrap around in `i32`
+        let far = [Command::Forward(100_000), Command::Down(100_000)];
+        assert_eq!(part_1(&far), Ok(10_000_000_000));
+
+        let steep = [
+            Command::Down(i32::MAX),
+            Command::Down(i32::MAX),
+            Command::Forward(1000),
+        ];
+        assert_eq!(part_2(&steep), Ok(1000 * 1000 * 2 * i64::from(i32::MAX)));
+    }
+
+    #[test]
+    fn overflow_errors() {
+        let input = "down 2147483647\ndown 2147483647\nforward 2147483647\nforward 2147483647\n";
+        let commands = Day02::parse(input).unwrap();
+        assert_eq!(
+            part_2(&commands),
+            Err(OverflowError::Command {
+                index: 3,
+                command: Command::Forward(i32::MAX)
+            })
+        );
+        assert_eq!(
+            Day02::part_2(&commands).unwrap_err().to_string(),
+            "no solution: the position overflows at command 4 (`forward 2147483647`)"
+        );
+
+        let wide = [Command::Forward(i32::MAX), Command::Down(i32::MAX)].repeat(3);
+        assert_eq!(part_1(&wide), Err(OverflowError::Product));
+        assert_eq!(
+            Day02::part_1(&wide).unwrap_err().to_string(),
+            "no solution: horizontal position times depth overflows"
+        );
+    }
+
+    #[test]
+    fn command_display_round_trips() {
+        for cmd in TEST_DATA {
+            assert_eq!(cmd.to_string().parse(), Ok(**cmd));
+        }
+        assert_eq!(Command::Up(-2).to_string(), "up -2");
+    }
+
+    #[test]
+    fn command_from_str() {
+        assert_eq!("forward 5".parse(), Ok(Command::Forward(5)));
+        assert_eq!("  down\t-3 ".parse(), Ok(Command::Down(-3)));
+        assert_eq!(
+            "backward 5".parse::<Command>(),
+            Err(CommandError::UnknownVerb("backward".into()))
+        );
+        assert_eq!(
+            "".parse::<Command>(),
+            Err(CommandError::UnknownVerb("".into()))
+        );
+        assert_eq!("up".parse::<Command>(), Err(CommandError::MissingArgument));
+        assert!(matches!(
+            "up five".parse::<Command>(),
+            Err(CommandError::InvalidArgument(argument, _)) if argument == "five"
+        ));
+        assert_eq!(
+            "up 5 meters now".parse::<Command>(),
+            Err(CommandError::TrailingGarbage("meters now".into()))
+        );
+        assert_eq!(
+            "up 5 meters".parse::<Command>().unwrap_err().to_string(),
+            "unexpected `meters` after the number"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |input| match Day02::parse(input) {
+            Err(aoc_common::Error::Parse(err)) => err,
+            _ => panic!("expected a parse error"),
+        };
+
+        assert_eq!(
+            err("forward 5\nbackward 3\n"),
+            ParseError::new(2, 1, "backward", "unknown command")
+        );
+        assert_eq!(
+            err("forward 5\ndown x\n"),
+            ParseError::new(2, 6, "x", "invalid digit found in string")
+        );
+        assert_eq!(
+            err("forward\n"),
+            ParseError::new(1, 1, "forward", "expected a command and a number")
+        );
+        assert_eq!(
+            err("forward 5 x\n"),
+            ParseError::new(1, 11, "x", "unexpected text after the number")
+        );
+    }
+
+    #[test]
+    fn parse_collects_all_errors() {
+        let errors = match Day02::parse("forward 5\nbackward 3\ndown 2\n\nup x\nup 1 2\n") {
+            Err(aoc_common::Error::ParseMany(first, rest)) => {
+                std::iter::once(first).chain(rest).collect::<Vec<_>>()
+            }
+            other => panic!("expected several parse errors, got {:?}", other),
+        };
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|err| (err.line, err.column, err.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, 1, "backward"), (5, 4, "x"), (6, 6, "2")]
+        );
+    }
+}
@@ -1,86 +1,172 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_common::{Part, Solution};
+use clap::{Args, Parser, Subcommand};
+use d02::{Condition, Debugger, Trajectory};
+use std::path::PathBuf;
 
+#[derive(Parser)]
+#[command(about = "Day 2: Dive!", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Subcommand)]
 enum Command {
-    Forward(i32),
-    Down(i32),
-    Up(i32),
+    /// Record the position after every command as CSV
+    Trajectory(TrajectoryArgs),
+    /// Run a script with repeats, macros and comments
+    Script(ScriptArgs),
+    /// Print a shortest list of commands reaching a position
+    Plan(PlanArgs),
+    /// Step through the input interactively under both parts
+    Debug(DebugArgs),
 }
 
-fn main() {
-    let input: Vec<Command> = BufReader::new(File::open("input.txt").unwrap())
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            let (command, number) = line.trim().split_once(" ").unwrap();
-            let number: i32 = number.parse().unwrap();
-            match command {
-                "forward" => Command::Forward(number),
-                "down" => Command::Down(number),
-                "up" => Command::Up(number),
-                _ => panic!("unknown command: {}", command),
-            }
-        })
-        .collect();
+#[derive(Args)]
+struct TrajectoryArgs {
+    /// follow the rules of this part
+    #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// also report the first step deeper than this
+    #[arg(short, long, allow_negative_numbers = true)]
+    limit: Option<i64>,
+
+    /// file to write the CSV to, stdout if not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// input file, `-` to read from stdin
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+#[derive(Args)]
+struct ScriptArgs {
+    /// print the plain commands the script expands to instead
+    #[arg(long)]
+    expand: bool,
+
+    /// script file, `-` to read from stdin
+    #[arg(default_value = "-")]
+    input: String,
+}
+
+#[derive(Args)]
+struct PlanArgs {
+    /// follow the rules of this part
+    #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[arg(allow_negative_numbers = true)]
+    horizontal: i32,
+
+    #[arg(allow_negative_numbers = true)]
+    depth: i32,
+}
 
-    println!("part 1: {}", part_1(input.iter()));
-    println!("part 2: {}", part_2(input.iter()));
+#[derive(Args)]
+struct DebugArgs {
+    /// stop where a condition like `depth > 1000` or `2 aim < 0` holds
+    #[arg(short, long = "break")]
+    breakpoint: Vec<Condition>,
+
+    /// input file
+    #[arg(default_value = "input.txt")]
+    input: String,
+}
+
+fn part(number: u8) -> Part {
+    if number == 1 {
+        Part::One
+    } else {
+        Part::Two
+    }
 }
 
-fn part_1<'a>(input: impl Iterator<Item = &'a Command>) -> i32 {
-    let mut horz = 0;
-    let mut vert = 0;
+/// Reads and parses the commands in `path`, exiting on errors.
+fn read_commands(path: &str) -> Vec<d02::Command> {
+    let input = aoc_common::read_input(path).unwrap_or_else(|err| aoc_common::exit_with_error(err));
+    d02::Day02::parse(&input).unwrap_or_else(|err| {
+        aoc_common::exit_with_error(aoc_common::render_error(path, &input, &err))
+    })
+}
+
+fn trajectory(args: &TrajectoryArgs) {
+    let trajectory = Trajectory::record(&read_commands(&args.input), part(args.part))
+        .unwrap_or_else(|err| aoc_common::exit_with_error(format!("{}: {}", args.input, err)));
 
-    for cmd in input {
-        match cmd {
-            Command::Forward(n) => horz += n,
-            Command::Down(n) => vert += n,
-            Command::Up(n) => vert -= n,
+    let mut summary = format!("max depth: {}", trajectory.max_depth());
+    if let Some(limit) = args.limit {
+        match trajectory.first_deeper_than(limit) {
+            Some(step) => summary += &format!("\nfirst deeper than {}: step {}", limit, step),
+            None => summary += &format!("\nnever deeper than {}", limit),
         }
     }
 
-    horz * vert
+    // keep stdout usable as CSV when the trajectory goes there
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, trajectory.to_csv()).unwrap_or_else(|err| {
+                aoc_common::exit_with_error(format!("could not write {}: {}", path.display(), err))
+            });
+            println!("{}", summary);
+        }
+        None => {
+            print!("{}", trajectory.to_csv());
+            eprintln!("{}", summary);
+        }
+    }
 }
 
-fn part_2<'a>(input: impl Iterator<Item = &'a Command>) -> i32 {
-    let mut horz = 0;
-    let mut vert = 0;
-    let mut aim = 0;
+fn script(args: &ScriptArgs) {
+    let source =
+        aoc_common::read_input(&args.input).unwrap_or_else(|err| aoc_common::exit_with_error(err));
+    let commands = d02::expand(&source).unwrap_or_else(|err| {
+        aoc_common::exit_with_error(aoc_common::render_error(&args.input, &source, &err))
+    });
 
-    for cmd in input {
-        match cmd {
-            Command::Forward(n) => {
-                horz += n;
-                vert += aim * n;
+    if args.expand {
+        commands.iter().for_each(|command| println!("{}", command));
+    } else {
+        for (part, answer) in [(1, d02::part_1(&commands)), (2, d02::part_2(&commands))] {
+            match answer {
+                Ok(answer) => println!("part {}: {}", part, answer),
+                Err(err) => println!("part {}: {}", part, err),
             }
-            Command::Down(n) => aim += n,
-            Command::Up(n) => aim -= n,
         }
     }
+}
 
-    horz * vert
+fn plan(args: &PlanArgs) {
+    for command in d02::plan(args.horizontal, args.depth, part(args.part)) {
+        println!("{}", command);
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &[&Command] = &[
-        &Command::Forward(5),
-        &Command::Down(5),
-        &Command::Forward(8),
-        &Command::Up(3),
-        &Command::Down(8),
-        &Command::Forward(2),
-    ];
-
-    #[test]
-    fn part_1_works() {
-        assert_eq!(part_1(TEST_DATA.iter().cloned()), 150);
+fn debug(args: &DebugArgs) {
+    let mut debugger = Debugger::new(read_commands(&args.input));
+    for &condition in &args.breakpoint {
+        debugger.add_breakpoint(condition);
+    }
+
+    if let Err(err) = d02::repl(&mut debugger, std::io::stdin().lock(), std::io::stdout()) {
+        aoc_common::exit_with_error(err);
     }
+}
+
+fn main() {
+    let cli = Cli::parse();
 
-    #[test]
-    fn part_2_works() {
-        assert_eq!(part_2(TEST_DATA.iter().cloned()), 900);
+    match &cli.command {
+        None => aoc_common::run_file::<d02::Day02>(&cli.input),
+        Some(Command::Trajectory(args)) => trajectory(args),
+        Some(Command::Script(args)) => script(args),
+        Some(Command::Plan(args)) => plan(args),
+        Some(Command::Debug(args)) => debug(args),
     }
 }
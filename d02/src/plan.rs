@@ -0,0 +1,173 @@
+use crate::Command;
+use aoc_common::Part;
+
+/// Changes the depth, or the aim under the rules of part 2, by `n`, going
+/// `up` for negative numbers where possible.
+fn descend(n: i32) -> Command {
+    match n.checked_neg() {
+        Some(up) if n < 0 => Command::Up(up),
+        _ => Command::Down(n),
+    }
+}
+
+/// A shortest list of commands that takes the submarine from the surface to
+/// `horizontal` and `depth` under the rules of `part`.
+///
+/// Under the rules of part 1 that takes at most a `forward` and a `down` or
+/// `up`. Under the rules of part 2 the depth only changes when moving
+/// forward with some aim, which needs two commands if `depth` is a multiple
+/// of `horizontal`, and three otherwise: move forward most of the way,
+/// change the aim to `depth`, and make a single step.
+pub fn plan(horizontal: i32, depth: i32, part: Part) -> Vec<Command> {
+    let forward = |n: i32| Some(n).filter(|&n| n != 0).map(Command::Forward);
+
+    if part == Part::One || depth == 0 {
+        let down = Some(depth).filter(|&d| d != 0).map(descend);
+        return forward(horizontal).into_iter().chain(down).collect();
+    }
+
+    if let Some(aim) = depth
+        .checked_rem(horizontal)
+        .filter(|&rem| rem == 0)
+        .and_then(|_| depth.checked_div(horizontal))
+    {
+        return vec![descend(aim), Command::Forward(horizontal)];
+    }
+
+    // the rest of the way only overflows for i32::MIN, where stepping back
+    // works instead; -depth can't overflow then, as i32::MIN is a multiple
+    // of itself
+    let (rest, step, aim) = match horizontal.checked_sub(1) {
+        Some(rest) => (rest, 1, depth),
+        None => (horizontal + 1, -1, -depth),
+    };
+    [forward(rest), Some(descend(aim)), forward(step)]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part_1, part_2, Position};
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    fn reaches(plan: &[Command], horizontal: i32, depth: i32, part: Part) -> bool {
+        let end = Position::follow(plan, part).unwrap();
+        let answer = match part {
+            Part::One => part_1(plan),
+            Part::Two => part_2(plan),
+        };
+
+        (end.horizontal, end.depth) == (horizontal.into(), depth.into())
+            && answer == Ok(i64::from(horizontal) * i64::from(depth))
+    }
+
+    #[test]
+    fn test_plans() {
+        for part in Part::ALL {
+            assert!(plan(0, 0, part).is_empty());
+            assert_eq!(plan(7, 0, part), vec![Command::Forward(7)]);
+        }
+
+        assert_eq!(
+            plan(15, 10, Part::One),
+            vec![Command::Forward(15), Command::Down(10)]
+        );
+        assert_eq!(plan(0, -3, Part::One), vec![Command::Up(3)]);
+        assert_eq!(
+            plan(15, 60, Part::Two),
+            vec![Command::Down(4), Command::Forward(15)]
+        );
+        assert_eq!(
+            plan(15, 61, Part::Two),
+            vec![Command::Forward(14), Command::Down(61), Command::Forward(1)]
+        );
+        assert_eq!(
+            plan(0, -8, Part::Two),
+            vec![Command::Forward(-1), Command::Up(8), Command::Forward(1)]
+        );
+    }
+
+    #[test]
+    fn test_extreme_plans() {
+        for horizontal in [i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX] {
+            for depth in [i32::MIN, i32::MIN + 1, -7, 0, 7, i32::MAX] {
+                for part in Part::ALL {
+                    let plan = plan(horizontal, depth, part);
+                    assert!(
+                        reaches(&plan, horizontal, depth, part),
+                        "{:?} for {} {} under part {}",
+                        plan,
+                        horizontal,
+                        depth,
+                        part
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            plan(-1, i32::MIN, Part::One),
+            vec![Command::Forward(-1), Command::Down(i32::MIN)]
+        );
+    }
+
+    /// The fewest commands reaching each position that takes at most two,
+    /// trying all commands with arguments up to `limit`.
+    fn brute_force(limit: i32, part: Part) -> HashMap<(i64, i64), usize> {
+        let commands: Vec<Command> = (-limit..=limit)
+            .flat_map(|n| [Command::Forward(n), Command::Down(n), Command::Up(n)])
+            .collect();
+
+        let mut fewest = HashMap::new();
+        let mut reached = vec![Position::default()];
+        let mut length = 0;
+        loop {
+            for position in &reached {
+                fewest
+                    .entry((position.horizontal, position.depth))
+                    .or_insert(length);
+            }
+            if length == 2 {
+                return fewest;
+            }
+            reached = reached
+                .iter()
+                .flat_map(|p| commands.iter().filter_map(|c| p.step(c, part)))
+                .collect();
+            length += 1;
+        }
+    }
+
+    #[test]
+    fn test_plans_are_shortest() {
+        for part in Part::ALL {
+            // no aim or step in a plan of two takes more than 36
+            let fewest = brute_force(36, part);
+            for horizontal in -6..=6 {
+                for depth in -6..=6 {
+                    let plan = plan(horizontal, depth, part);
+                    let expected = fewest
+                        .get(&(horizontal.into(), depth.into()))
+                        .copied()
+                        .unwrap_or(3);
+                    assert_eq!(plan.len(), expected, "{:?}", plan);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn plans_reach_the_target(horizontal: i32, depth: i32) {
+            for part in Part::ALL {
+                let plan = plan(horizontal, depth, part);
+                prop_assert!(reaches(&plan, horizontal, depth, part), "{:?}", plan);
+                let most = if part == Part::One { 2 } else { 3 };
+                prop_assert!(plan.len() <= most);
+            }
+        }
+    }
+}
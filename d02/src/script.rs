@@ -0,0 +1,380 @@
+use crate::Command;
+use aoc_common::{InputLine, ParseError, Result};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+/// The most commands a script may expand to, so a few nested repeats can't
+/// exhaust memory.
+pub const MAX_COMMANDS: usize = 1 << 24;
+
+const KEYWORDS: &[&str] = &["forward", "down", "up", "back", "repeat", "macro"];
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    text: &'a str,
+    line: InputLine<'a>,
+}
+
+impl Token<'_> {
+    fn error(&self, message: impl Display) -> ParseError {
+        self.line.error(self.text, message)
+    }
+}
+
+/// Splits a script into words and braces, dropping `#` comments.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for line in aoc_common::numbered_lines(source) {
+        let mut rest = line.text.split('#').next().unwrap_or_default();
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            let len = if rest.starts_with(['{', '}']) {
+                1
+            } else {
+                rest.find(|c: char| c.is_whitespace() || c == '{' || c == '}')
+                    .unwrap_or(rest.len())
+            };
+            tokens.push(Token {
+                text: &rest[..len],
+                line,
+            });
+            rest = &rest[len..];
+        }
+    }
+    tokens
+}
+
+fn is_name(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && text
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        && !KEYWORDS.contains(&text)
+}
+
+#[derive(Clone, Debug)]
+enum Statement<'a> {
+    Move(Command),
+    Repeat(Token<'a>, usize, Vec<Statement<'a>>),
+    Call(Token<'a>),
+}
+
+struct Parser<'a> {
+    tokens: Peekable<std::vec::IntoIter<Token<'a>>>,
+    macros: HashMap<&'a str, Vec<Statement<'a>>>,
+}
+
+impl<'a> Parser<'a> {
+    fn next_after(&mut self, previous: Token<'a>, expected: &str) -> Result<Token<'a>, ParseError> {
+        self.tokens.next().ok_or_else(|| previous.error(expected))
+    }
+
+    fn number<T>(&mut self, verb: Token<'a>) -> Result<(T, Token<'a>), ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.next_after(verb, "expected a number after this")?;
+        Ok((token.line.parse(token.text)?, token))
+    }
+
+    fn next_is_number(&mut self) -> bool {
+        self.tokens
+            .peek()
+            .is_some_and(|token| token.text.parse::<i64>().is_ok())
+    }
+
+    fn open(&mut self, previous: Token<'a>) -> Result<Token<'a>, ParseError> {
+        let token = self.next_after(previous, "expected `{` after this")?;
+        match token.text {
+            "{" => Ok(token),
+            _ => Err(token.error("expected `{`")),
+        }
+    }
+
+    /// The statements up to the `}` matching `open`, or up to the end of the
+    /// script at the top level, where `open` is `None`.
+    fn block(&mut self, open: Option<Token<'a>>) -> Result<Vec<Statement<'a>>, ParseError> {
+        let mut statements = Vec::new();
+        while let Some(token) = self.tokens.next() {
+            let statement = match token.text {
+                "}" if open.is_some() => return Ok(statements),
+                "{" | "}" => return Err(token.error("unexpected brace")),
+                "forward" => Statement::Move(Command::Forward(self.number(token)?.0)),
+                "down" => Statement::Move(Command::Down(self.number(token)?.0)),
+                "up" => Statement::Move(Command::Up(self.number(token)?.0)),
+                "back" => {
+                    let (n, number): (i32, _) = self.number(token)?;
+                    let n = n
+                        .checked_neg()
+                        .ok_or_else(|| number.error("too far to go back"))?;
+                    Statement::Move(Command::Forward(n))
+                }
+                "repeat" => {
+                    let (count, number) = self.number(token)?;
+                    let open = self.open(number)?;
+                    Statement::Repeat(token, count, self.block(Some(open))?)
+                }
+                "macro" => {
+                    if open.is_some() {
+                        return Err(token.error("macros can only be defined at the top level"));
+                    }
+                    let name = self.next_after(token, "expected a macro name after this")?;
+                    if !is_name(name.text) {
+                        return Err(name.error("invalid macro name"));
+                    }
+                    let open = self.open(name)?;
+                    let body = self.block(Some(open))?;
+                    if self.macros.insert(name.text, body).is_some() {
+                        return Err(name.error("macro is already defined"));
+                    }
+                    continue;
+                }
+                // like `backward 3`, as macros don't take a number
+                text if is_name(text) && !self.next_is_number() => Statement::Call(token),
+                _ => return Err(token.error("unknown command")),
+            };
+            statements.push(statement);
+        }
+
+        match open {
+            Some(open) => Err(open.error("unclosed `{`")),
+            None => Ok(statements),
+        }
+    }
+}
+
+/// A dive plan in the extended command language: the plain commands of the
+/// puzzle, `back n` for going backwards, `repeat n { ... }` blocks, macros
+/// defined at the top level with `macro name { ... }` and called by their
+/// name, and comments from `#` to the end of the line.
+///
+/// ```text
+/// macro zigzag { down 2 forward 3 up 2 }
+/// repeat 4 {
+///     zigzag  # one zig and one zag
+/// }
+/// back 5
+/// ```
+#[derive(Clone, Debug)]
+pub struct Script<'a> {
+    main: Vec<Statement<'a>>,
+    macros: HashMap<&'a str, Vec<Statement<'a>>>,
+}
+
+impl<'a> Script<'a> {
+    /// Parses `source`, stopping at the first syntax error. Macros may be
+    /// called before they are defined.
+    pub fn parse(source: &'a str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(source).into_iter().peekable(),
+            macros: HashMap::new(),
+        };
+        let main = parser.block(None)?;
+
+        Ok(Script {
+            main,
+            macros: parser.macros,
+        })
+    }
+
+    /// The plain commands the script stands for, which [`part_1`] and
+    /// [`part_2`] can follow.
+    ///
+    /// [`part_1`]: crate::part_1
+    /// [`part_2`]: crate::part_2
+    pub fn expand(&self) -> Result<Vec<Command>> {
+        let mut commands = Vec::new();
+        self.expand_into(&self.main, &mut commands, &mut Vec::new())?;
+        Ok(commands)
+    }
+
+    fn expand_into(
+        &self,
+        statements: &[Statement<'a>],
+        commands: &mut Vec<Command>,
+        calls: &mut Vec<&'a str>,
+    ) -> Result<(), ParseError> {
+        let too_many =
+            |token: &Token| token.error(format!("expands to more than {} commands", MAX_COMMANDS));
+
+        for statement in statements {
+            match statement {
+                Statement::Move(command) => commands.push(*command),
+                Statement::Repeat(token, count, body) => {
+                    let mut once = Vec::new();
+                    self.expand_into(body, &mut once, calls)?;
+                    if once.len().saturating_mul(*count) > MAX_COMMANDS - commands.len() {
+                        return Err(too_many(token));
+                    }
+                    for _ in 0..*count {
+                        commands.extend_from_slice(&once);
+                    }
+                }
+                Statement::Call(name) => {
+                    let body = self
+                        .macros
+                        .get(name.text)
+                        .ok_or_else(|| name.error("unknown command or macro"))?;
+                    if calls.contains(&name.text) {
+                        return Err(name.error("macro calls itself"));
+                    }
+
+                    calls.push(name.text);
+                    self.expand_into(body, commands, calls)?;
+                    calls.pop();
+                    if commands.len() > MAX_COMMANDS {
+                        return Err(too_many(name));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses and expands `source` in one go.
+pub fn expand(source: &str) -> Result<Vec<Command>> {
+    Script::parse(source)?.expand()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part_1, part_2, Day02};
+    use aoc_common::{Error, Solution};
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    fn err(source: &str) -> ParseError {
+        match expand(source) {
+            Err(Error::Parse(err)) => err,
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_plain_commands() {
+        let commands = expand(EXAMPLE).unwrap();
+        assert_eq!(commands, Day02::parse(EXAMPLE).unwrap());
+        assert_eq!(part_1(commands.iter()), Ok(150));
+        assert_eq!(part_2(commands.iter()), Ok(900));
+        assert!(expand("# nothing to do\n\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_extensions() {
+        let source = "\
+# the example, once forward and once back
+macro example {
+    forward 5 down 5 forward 8
+    up 3 down 8 forward 2  # end of the example
+}
+example
+repeat 2 { back 1 } repeat 0 { down 100 }
+";
+        let commands = expand(source).unwrap();
+        assert_eq!(commands.len(), 8);
+        assert_eq!(commands[6..], [Command::Forward(-1), Command::Forward(-1)]);
+        assert_eq!(part_1(commands.iter()), Ok(13 * 10));
+        assert_eq!(part_2(commands.iter()), Ok(13 * 40));
+    }
+
+    #[test]
+    fn test_nesting() {
+        let source =
+            "repeat 3 { zig repeat 2 { forward 1 } }\nmacro zig {down 1 zag}\nmacro zag{up 2}";
+        let commands = expand(source).unwrap();
+        let once = [
+            Command::Down(1),
+            Command::Up(2),
+            Command::Forward(1),
+            Command::Forward(1),
+        ];
+        assert_eq!(commands, once.repeat(3));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert_eq!(
+            err("forward 5\nbackward 3\n"),
+            ParseError::new(2, 1, "backward", "unknown command")
+        );
+        assert_eq!(
+            err("forward 5\nbackward\n"),
+            ParseError::new(2, 1, "backward", "unknown command or macro")
+        );
+        assert_eq!(
+            err("forward\n"),
+            ParseError::new(1, 1, "forward", "expected a number after this")
+        );
+        assert_eq!(
+            err("up x"),
+            ParseError::new(1, 4, "x", "invalid digit found in string")
+        );
+        assert_eq!(
+            err("back -2147483648"),
+            ParseError::new(1, 6, "-2147483648", "too far to go back")
+        );
+        assert_eq!(
+            err("repeat -1 { up 1 }").message,
+            "invalid digit found in string"
+        );
+        assert_eq!(
+            err("repeat 2\n  {\n  up 1\n"),
+            ParseError::new(2, 3, "{", "unclosed `{`")
+        );
+        assert_eq!(
+            err("repeat 2 up 1"),
+            ParseError::new(1, 10, "up", "expected `{`")
+        );
+        assert_eq!(
+            err("up 1 }"),
+            ParseError::new(1, 6, "}", "unexpected brace")
+        );
+        assert_eq!(err("forward 1.5").message, "invalid digit found in string");
+        assert_eq!(err("42").message, "unknown command");
+    }
+
+    #[test]
+    fn test_macro_errors() {
+        assert_eq!(
+            err("repeat 2 { macro m { up 1 } }").message,
+            "macros can only be defined at the top level"
+        );
+        assert_eq!(
+            err("macro up { down 1 }"),
+            ParseError::new(1, 7, "up", "invalid macro name")
+        );
+        assert_eq!(
+            err("macro m { up 1 }\nmacro m { down 1 }"),
+            ParseError::new(2, 7, "m", "macro is already defined")
+        );
+        assert_eq!(
+            err("a\nmacro a { b }\nmacro b { up 1 a }"),
+            ParseError::new(3, 16, "a", "macro calls itself")
+        );
+        // only calls that are actually expanded count
+        assert!(expand("macro a { a }").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_too_many_commands() {
+        let huge = "repeat 4096 { repeat 4096 { repeat 2 { up 1 } } }";
+        assert_eq!(
+            err(huge),
+            ParseError::new(1, 1, "repeat", "expands to more than 16777216 commands")
+        );
+
+        let nested = "macro m { repeat 8192 { up 1 } }\nforward 1 repeat 4096 { m }";
+        assert_eq!(
+            err(nested),
+            ParseError::new(2, 11, "repeat", "expands to more than 16777216 commands")
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::Command;
+use aoc_common::Part;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// Where the submarine is. The aim stays 0 under the rules of part 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    /// The position after following `command` under the rules of `part`, or
+    /// `None` if it doesn't fit into an `i64`.
+    pub fn step(self, command: &Command, part: Part) -> Option<Self> {
+        let Position {
+            mut horizontal,
+            mut depth,
+            mut aim,
+        } = self;
+
+        match (part, *command) {
+            (_, Command::Forward(n)) => {
+                horizontal = horizontal.checked_add(n.into())?;
+                if part == Part::Two {
+                    depth = depth.checked_add(aim.checked_mul(n.into())?)?;
+                }
+            }
+            (Part::One, Command::Down(n)) => depth = depth.checked_add(n.into())?,
+            (Part::One, Command::Up(n)) => depth = depth.checked_sub(n.into())?,
+            (Part::Two, Command::Down(n)) => aim = aim.checked_add(n.into())?,
+            (Part::Two, Command::Up(n)) => aim = aim.checked_sub(n.into())?,
+        }
+
+        Some(Position {
+            horizontal,
+            depth,
+            aim,
+        })
+    }
+
+    /// Horizontal position times depth, the puzzle answer.
+    pub fn product(self) -> Result<i64, OverflowError> {
+        self.horizontal
+            .checked_mul(self.depth)
+            .ok_or(OverflowError::Product)
+    }
+
+    /// Follows all `commands` from the surface.
+    pub fn follow<'a>(
+        commands: impl IntoIterator<Item = &'a Command>,
+        part: Part,
+    ) -> Result<Self, OverflowError> {
+        commands.into_iter().enumerate().try_fold(
+            Position::default(),
+            |position, (index, command)| {
+                position.step(command, part).ok_or(OverflowError::Command {
+                    index,
+                    command: *command,
+                })
+            },
+        )
+    }
+}
+
+/// A position or answer too large for an `i64`.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowError {
+    /// Following the command at the 0-based `index` overflows.
+    #[error("the position overflows at command {} (`{command}`)", .index + 1)]
+    Command { index: usize, command: Command },
+    #[error("horizontal position times depth overflows")]
+    Product,
+}
+
+impl From<OverflowError> for aoc_common::Error {
+    fn from(err: OverflowError) -> Self {
+        aoc_common::Error::NoSolution(err.to_string())
+    }
+}
+
+/// Every position the submarine passes through while following a list of
+/// commands, starting at the surface.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trajectory {
+    part: Part,
+    commands: Vec<Command>,
+    positions: Vec<Position>,
+}
+
+impl Trajectory {
+    /// Follows `commands` under the rules of `part`.
+    pub fn record<'a>(
+        commands: impl IntoIterator<Item = &'a Command>,
+        part: Part,
+    ) -> Result<Self, OverflowError> {
+        let commands: Vec<Command> = commands.into_iter().copied().collect();
+        let mut positions = Vec::with_capacity(commands.len() + 1);
+        positions.push(Position::default());
+        for (index, command) in commands.iter().enumerate() {
+            let next = positions[index]
+                .step(command, part)
+                .ok_or(OverflowError::Command {
+                    index,
+                    command: *command,
+                })?;
+            positions.push(next);
+        }
+
+        Ok(Trajectory {
+            part,
+            commands,
+            positions,
+        })
+    }
+
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// The starting position followed by the position after every command,
+    /// so step `i` is the position after command `i - 1`.
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    pub fn last(&self) -> Position {
+        self.positions[self.positions.len() - 1]
+    }
+
+    /// The deepest depth along the way, at least 0 for the surface.
+    pub fn max_depth(&self) -> i64 {
+        self.positions.iter().map(|p| p.depth).max().unwrap_or(0)
+    }
+
+    /// The first step where the depth is greater than `limit`.
+    pub fn first_deeper_than(&self, limit: i64) -> Option<usize> {
+        self.positions.iter().position(|p| p.depth > limit)
+    }
+
+    /// The steps as CSV with a header, one row per position.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,command,horizontal,depth,aim\n");
+        for (step, position) in self.positions.iter().enumerate() {
+            let command = match step {
+                0 => String::new(),
+                _ => self.commands[step - 1].to_string(),
+            };
+            writeln!(
+                csv,
+                "{},{},{},{},{}",
+                step, command, position.horizontal, position.depth, position.aim
+            )
+            .unwrap();
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &[Command] = &[
+        Command::Forward(5),
+        Command::Down(5),
+        Command::Forward(8),
+        Command::Up(3),
+        Command::Down(8),
+        Command::Forward(2),
+    ];
+
+    fn depths(trajectory: &Trajectory) -> Vec<i64> {
+        trajectory.positions().iter().map(|p| p.depth).collect()
+    }
+
+    #[test]
+    fn test_record() {
+        let one = Trajectory::record(TEST_DATA, Part::One).unwrap();
+        assert_eq!(depths(&one), vec![0, 0, 5, 5, 2, 10, 10]);
+        assert_eq!(
+            one.last(),
+            Position {
+                horizontal: 15,
+                depth: 10,
+                aim: 0
+            }
+        );
+
+        let two = Trajectory::record(TEST_DATA, Part::Two).unwrap();
+        assert_eq!(depths(&two), vec![0, 0, 0, 40, 40, 40, 60]);
+        assert_eq!(
+            two.last(),
+            Position {
+                horizontal: 15,
+                depth: 60,
+                aim: 10
+            }
+        );
+        assert_eq!(two.positions()[4].aim, 2);
+        assert_eq!(two.commands(), TEST_DATA);
+    }
+
+    #[test]
+    fn test_queries() {
+        let one = Trajectory::record(TEST_DATA, Part::One).unwrap();
+        assert_eq!(one.max_depth(), 10);
+        assert_eq!(one.first_deeper_than(4), Some(2));
+        assert_eq!(one.first_deeper_than(5), Some(5));
+        assert_eq!(one.first_deeper_than(10), None);
+        assert_eq!(one.first_deeper_than(-1), Some(0));
+
+        let two = Trajectory::record(TEST_DATA, Part::Two).unwrap();
+        assert_eq!(two.max_depth(), 60);
+        assert_eq!(two.first_deeper_than(40), Some(6));
+
+        let up = Trajectory::record(&[Command::Up(3), Command::Forward(1)], Part::One).unwrap();
+        assert_eq!(up.max_depth(), 0);
+        assert_eq!(up.last().depth, -3);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let deep = [
+            Command::Down(i32::MAX),
+            Command::Down(i32::MAX),
+            Command::Forward(i32::MAX),
+            Command::Forward(i32::MAX),
+        ];
+        assert_eq!(
+            Trajectory::record(&deep, Part::Two),
+            Err(OverflowError::Command {
+                index: 3,
+                command: Command::Forward(i32::MAX)
+            })
+        );
+        let one = Trajectory::record(&deep, Part::One).unwrap();
+        assert_eq!(one.max_depth(), 2 * i64::from(i32::MAX));
+        assert_eq!(Position::follow(&deep, Part::One), Ok(one.last()));
+        assert_eq!(
+            Position::follow(&deep, Part::Two).unwrap_err().to_string(),
+            "the position overflows at command 4 (`forward 2147483647`)"
+        );
+
+        let far = Position {
+            horizontal: i64::MAX,
+            depth: 2,
+            aim: 0,
+        };
+        assert_eq!(far.product(), Err(OverflowError::Product));
+        assert_eq!(far.step(&Command::Forward(1), Part::One), None);
+        assert_eq!(
+            far.step(&Command::Forward(-1), Part::Two)
+                .unwrap()
+                .horizontal,
+            i64::MAX - 1
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = Trajectory::record(&[], Part::Two).unwrap();
+        assert_eq!(empty.positions(), &[Position::default()]);
+        assert_eq!(empty.max_depth(), 0);
+        assert_eq!(
+            empty.to_csv(),
+            "step,command,horizontal,depth,aim\n0,,0,0,0\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = Trajectory::record(&TEST_DATA[..3], Part::Two)
+            .unwrap()
+            .to_csv();
+        assert_eq!(
+            csv,
+            "step,command,horizontal,depth,aim\n\
+             0,,0,0,0\n\
+             1,forward 5,5,0,0\n\
+             2,down 5,5,0,5\n\
+             3,forward 8,13,40,5\n"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use aoc_common::{InputLine, ParseError, Result, Solution};
+use bitvec::prelude::*;
+
+/// Width of the numbers in the puzzle input.
+const BITS: usize = 12;
+
+/// Day 3: Binary Diagnostic
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<BitVec<usize, Msb0>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(aoc_common::numbered_lines(input)
+            .map(|line| parse_number(&line))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part_1::<_, _, BITS>(input.iter()))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(part_2::<_, _, BITS>(input.iter()))
+    }
+}
+
+fn parse_number(line: &InputLine) -> Result<BitVec<usize, Msb0>, ParseError> {
+    if let Some((i, c)) = line
+        .text
+        .char_indices()
+        .find(|&(_, c)| c != '0' && c != '1')
+    {
+        let c = &line.text[i..i + c.len_utf8()];
+        return Err(line.error(c, "expected `0` or `1`"));
+    }
+    if line.text.len() != BITS {
+        return Err(line.error(line.text, format!("expected {} bits", BITS)));
+    }
+
+    Ok(line.text.chars().map(|c| c == '1').collect())
+}
+
+/// Power consumption: gamma rate times epsilon rate of `N`-bit numbers.
+pub fn part_1<T, I, const N: usize>(input: I) -> usize
+where
+    T: AsRef<BitSlice<usize, Msb0>>,
+    I: Iterator<Item = T>,
+{
+    let mut count_1 = [0; N];
+    let mut count_0 = [0; N];
+
+    for bytes in input {
+        for i in 0..N {
+            if bytes.as_ref()[i] {
+                count_1[i] += 1;
+            } else {
+                count_0[i] += 1;
+            }
+        }
+    }
+
+    let mut gamma = bitvec![usize, Msb0; 0; N];
+    let mut epsilon = bitvec![usize, Msb0; 0; N];
+
+    for i in 0..N {
+        if count_1[i] > count_0[i] {
+            gamma.set(i, true);
+        }
+        if count_1[i] < count_0[i] {
+            epsilon.set(i, true);
+        }
+    }
+
+    let gamma = gamma.load_le::<usize>();
+    let epsilon = epsilon.load_le::<usize>();
+
+    gamma * epsilon
+}
+
+fn count_bits<T, I>(input: I, position: usize) -> (usize, usize)
+where
+    T: AsRef<BitSlice<usize, Msb0>>,
+    I: Iterator<Item = T>,
+{
+    let mut count_1: usize = 0;
+    let mut count_0: usize = 0;
+
+    for bs in input {
+        if bs.as_ref()[position] {
+            count_1 += 1;
+        } else {
+            count_0 += 1;
+        }
+    }
+
+    (count_1, count_0)
+}
+
+/// Life support rating: oxygen generator rating times CO2 scrubber rating of
+/// `N`-bit numbers.
+pub fn part_2<T, I, const N: usize>(input: I) -> usize
+where
+    T: AsRef<BitSlice<usize, Msb0>>,
+    I: Iterator<Item = T>,
+{
+    let numbers: Vec<BitVec<usize, Msb0>> = input.map(|bs| bs.as_ref().to_bitvec()).collect();
+    part_2_inner::<_, _, N>(numbers.iter(), true) * part_2_inner::<_, _, N>(numbers.iter(), false)
+}
+
+fn part_2_inner<T, I, const N: usize>(input: I, reverse: bool) -> usize
+where
+    T: AsRef<BitSlice<usize, Msb0>>,
+    I: Iterator<Item = T>,
+{
+    let mut numbers: Vec<BitVec<usize, Msb0>> = input.map(|bs| bs.as_ref().to_bitvec()).collect();
+
+    for bit in 0..N {
+        let (ones, zeroes) = count_bits(numbers.iter(), bit);
+        let wanted: bool = if reverse {
+            ones < zeroes
+        } else {
+            ones >= zeroes
+        };
+
+        numbers = numbers
+            .iter()
+            .filter(|bv| bv[bit] == wanted)
+            .cloned()
+            .collect();
+
+        if numbers.is_empty() {
+            panic!("removed all numbers!")
+        } else if numbers.len() == 1 {
+            let bf = &mut numbers[0];
+            bf.resize(N, false);
+            return bf.load::<usize>();
+        }
+    }
+    unreachable!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: [BitArray<[usize; 1], Msb0>; 12] = [
+        bitarr![const usize, Msb0; 0, 0, 1, 0, 0],
+        bitarr![const usize, Msb0; 1, 1, 1, 1, 0],
+        bitarr![const usize, Msb0; 1, 0, 1, 1, 0],
+        bitarr![const usize, Msb0; 1, 0, 1, 1, 1],
+        bitarr![const usize, Msb0; 1, 0, 1, 0, 1],
+        bitarr![const usize, Msb0; 0, 1, 1, 1, 1],
+        bitarr![const usize, Msb0; 0, 0, 1, 1, 1],
+        bitarr![const usize, Msb0; 1, 1, 1, 0, 0],
+        bitarr![const usize, Msb0; 1, 0, 0, 0, 0],
+        bitarr![const usize, Msb0; 1, 1, 0, 0, 1],
+        bitarr![const usize, Msb0; 0, 0, 0, 1, 0],
+        bitarr![const usize, Msb0; 0, 1, 0, 1, 0],
+    ];
+
+    #[test]
+    fn part_1_works() {
+        assert_eq!(part_1::<_, _, 5>(TEST_DATA.iter()), 198);
+    }
+
+    #[test]
+    fn part_2_works() {
+        assert_eq!(part_2::<_, _, 5>(TEST_DATA.iter()), 230);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |input| match Day03::parse(input) {
+            Err(aoc_common::Error::Parse(err)) => err,
+            _ => panic!("expected a parse error"),
+        };
+
+        assert_eq!(
+            err("000000000000\n00000x000000\n"),
+            ParseError::new(2, 6, "x", "expected `0` or `1`")
+        );
+        assert_eq!(
+            err("00100\n"),
+            ParseError::new(1, 1, "00100", "expected 12 bits")
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use aoc_common::{Error, InputLine, ParseError, Result, Solution};
+use std::str::FromStr;
+
+/// A 5x5 bingo board, parsed from five lines of five numbers.
+#[derive(Clone)]
+pub struct Board {
+    numbers: [u8; 25],
+    found: [bool; 25],
+    last_called: Option<u8>,
+    won: bool,
+}
+
+impl Board {
+    fn coord(x: u8, y: u8) -> usize {
+        (x * 5 + y).into()
+    }
+
+    /// Marks `number` as called. A board that has already won ignores
+    /// further numbers and returns `false`.
+    pub fn set(&mut self, number: u8) -> bool {
+        if self.won {
+            return false;
+        }
+        for i in 0..25 {
+            if self.numbers[i] == number {
+                self.found[i] = true;
+            }
+        }
+        self.last_called = Some(number);
+        true
+    }
+
+    /// Whether a full row or column is marked.
+    pub fn has_won(&mut self) -> bool {
+        for x in 0..5 {
+            if (0..5).all(|y| self.found[Self::coord(x, y)]) {
+                self.won = true;
+                return true;
+            }
+        }
+        for y in 0..5 {
+            if (0..5).all(|x| self.found[Self::coord(x, y)]) {
+                self.won = true;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Sum of all unmarked numbers times the last number called, 0 before
+    /// any number is.
+    pub fn score(&self) -> u32 {
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.found[*i])
+            .map(|(_, &n)| u32::from(n))
+            .sum::<u32>()
+            * u32::from(self.last_called.unwrap_or(0))
+    }
+}
+
+impl Board {
+    /// Parses a board from its five non-empty input lines.
+    pub fn from_lines(lines: &[InputLine]) -> Result<Self, ParseError> {
+        let mut b = Board {
+            numbers: [0; 25],
+            found: [false; 25],
+            last_called: None,
+            won: false,
+        };
+
+        if lines.len() != 5 {
+            let message = format!("expected 5 rows, found {}", lines.len());
+            return Err(match lines.get(5).or_else(|| lines.last()) {
+                Some(line) => line.error(line.text, message),
+                None => ParseError::new(1, 1, "", message),
+            });
+        }
+
+        for (x, line) in lines.iter().enumerate() {
+            let numbers: Vec<&str> = line.text.split_whitespace().collect();
+            if numbers.len() != 5 {
+                let message = format!("expected 5 numbers, found {}", numbers.len());
+                return Err(line.error(line.text, message));
+            }
+
+            for (y, num) in numbers.into_iter().enumerate() {
+                let idx = Self::coord(x as u8, y as u8);
+                b.numbers[idx] = line.parse(num)?;
+                b.found[idx] = false;
+            }
+        }
+        Ok(b)
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<InputLine> = aoc_common::numbered_lines(s).collect();
+        Board::from_lines(&lines)
+    }
+}
+
+impl std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for x in 0..5 {
+            for y in 0..5 {
+                let idx = Self::coord(x, y);
+                let num = format!("{:>2}", self.numbers[idx]);
+                if self.found[idx] {
+                    write!(f, "[{}] ", num)?;
+                } else {
+                    write!(f, " {}  ", num)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Day 4: Giant Squid
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = (Vec<u8>, Vec<Board>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = aoc_common::numbered_lines(input);
+        let numbers = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "input is empty"))?
+            .parse_separated(',')?;
+
+        // boards are separated by empty lines, so a gap in the line numbers
+        // starts the next board.
+        let mut boards = Vec::new();
+        let mut board_lines: Vec<InputLine> = Vec::new();
+        for line in lines {
+            if let Some(last) = board_lines.last() {
+                if line.number != last.number + 1 {
+                    boards.push(Board::from_lines(&board_lines)?);
+                    board_lines.clear();
+                }
+            }
+            board_lines.push(line);
+        }
+        if !board_lines.is_empty() {
+            boards.push(Board::from_lines(&board_lines)?);
+        }
+
+        Ok((numbers, boards))
+    }
+
+    fn part_1((numbers, boards): &Self::Input) -> Result<Self::Answer1> {
+        part_1(numbers.iter(), &mut boards.clone())
+            .ok_or_else(|| Error::NoSolution("no board has won".into()))
+    }
+
+    fn part_2((numbers, boards): &Self::Input) -> Result<Self::Answer2> {
+        part_2(numbers.iter(), &mut boards.clone())
+            .ok_or_else(|| Error::NoSolution("no board has won".into()))
+    }
+}
+
+/// Score of the first board to win, if any does.
+pub fn part_1<'a>(numbers: impl Iterator<Item = &'a u8>, boards: &mut [Board]) -> Option<u32> {
+    for n in numbers {
+        for b in boards.iter_mut() {
+            if b.set(*n) && b.has_won() {
+                return Some(b.score());
+            }
+        }
+    }
+    None
+}
+
+/// Score of the last board to win, if any does.
+pub fn part_2<'a>(numbers: impl Iterator<Item = &'a u8>, boards: &mut [Board]) -> Option<u32> {
+    let mut last_winner: Option<Board> = None;
+
+    for n in numbers {
+        for b in boards.iter_mut() {
+            if b.set(*n) && b.has_won() {
+                last_winner = Some(b.clone());
+            }
+        }
+    }
+
+    last_winner.map(|b| b.score())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMBERS: [u8; 27] = [
+        7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19, 3,
+        26, 1,
+    ];
+
+    const BOARDS: &str = "
+        22 13 17 11  0
+         8  2 23  4 24
+        21  9 14 16  7
+         6 10  3 18  5
+         1 12 20 15 19
+
+         3 15  0  2 22
+         9 18 13 17  5
+        19  8  7 25 23
+        20 11 10 24  4
+        14 21 16 12  6
+
+        14 21 17 24  4
+        10 16 15  9 19
+        18  8 23 26 20
+        22 11 13  6  5
+         2  0 12  3  7
+    ";
+
+    #[test]
+    fn test_part_1() {
+        let mut boards: Vec<Board> = BOARDS
+            .split("\n\n")
+            .map(|s| Board::from_str(s).unwrap())
+            .collect();
+
+        assert_eq!(part_1(NUMBERS.iter(), &mut boards), Some(4512));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut boards: Vec<Board> = BOARDS
+            .split("\n\n")
+            .map(|s| Board::from_str(s).unwrap())
+            .collect();
+
+        assert_eq!(part_2(NUMBERS.iter(), &mut boards), Some(1924));
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = format!("7,4,9,5,11\n{}", BOARDS);
+        let (numbers, boards) = Day04::parse(&input).unwrap();
+
+        assert_eq!(numbers, vec![7, 4, 9, 5, 11]);
+        assert_eq!(boards.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |input| match Day04::parse(input) {
+            Err(Error::Parse(err)) => err,
+            _ => panic!("expected a parse error"),
+        };
+
+        assert_eq!(
+            err("7,4,x\n"),
+            ParseError::new(1, 5, "x", "invalid digit found in string")
+        );
+        assert_eq!(
+            err("7,4\n\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4\n1 2 3 4 5\n1 2 3 4 5\n"),
+            ParseError::new(5, 1, "1 2 3 4", "expected 5 numbers, found 4")
+        );
+        assert_eq!(
+            err("7,4\n\n1 2 3 4 5\n1 2 3 4 5\n\n1 2 3 4 5\n"),
+            ParseError::new(4, 1, "1 2 3 4 5", "expected 5 rows, found 2")
+        );
+        assert_eq!(
+            Board::from_str("1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 300 4 5\n1 2 3 4 5").unwrap_err(),
+            ParseError::new(4, 5, "300", "number too large to fit in target type")
+        );
+    }
+
+    #[test]
+    fn test_set_after_winning() {
+        let mut board = Board::from_str(BOARDS.split("\n\n").next().unwrap()).unwrap();
+        assert_eq!(board.score(), 0);
+        for n in [22, 13, 17, 11, 0] {
+            assert!(board.set(n));
+        }
+        assert!(board.has_won());
+        let score = board.score();
+        assert!(!board.set(8));
+        assert_eq!(board.score(), score);
+    }
+
+    #[test]
+    fn test_large_score() {
+        let row = "255 255 255 255 255\n";
+        let mut board =
+            Board::from_str(&format!("{}254 255 255 255 255\n{}", row, row.repeat(3))).unwrap();
+        assert!(board.set(254));
+        assert!(!board.has_won());
+        assert_eq!(board.score(), 24 * 255 * 254);
+    }
+}
@@ -0,0 +1,315 @@
+use aoc_common::{InputLine, ParseError, Result, Solution};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A point on the ocean floor, written like `0,9`.
+#[derive(Hash, PartialEq, Eq, Clone)]
+pub struct Point {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl Point {
+    /// Parses `text`, a slice of `line`, reporting errors at their position
+    /// within the line.
+    pub fn parse_at(line: &InputLine, text: &str) -> Result<Self, ParseError> {
+        let (x, y) = text
+            .split_once(',')
+            .ok_or_else(|| line.error(text.trim(), "expected a point like `0,9`"))?;
+        Ok(Point {
+            x: line.parse(x)?,
+            y: line.parse(y)?,
+        })
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Point::parse_at(&InputLine::new(1, s), s)
+    }
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A line of hydrothermal vents, written like `0,9 -> 5,9`.
+pub struct Line {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Debug for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} -> {:?}", self.from, self.to)
+    }
+}
+
+impl Line {
+    /// Parses a line like `0,9 -> 5,9`, which has to be horizontal, vertical
+    /// or diagonal.
+    pub fn parse_at(line: &InputLine) -> Result<Self, ParseError> {
+        let (from, to) = line
+            .text
+            .split_once("->")
+            .ok_or_else(|| line.error(line.text, "expected a line like `0,9 -> 5,9`"))?;
+        let parsed = Line {
+            from: Point::parse_at(line, from)?,
+            to: Point::parse_at(line, to)?,
+        };
+
+        if !(parsed.is_horizontal() || parsed.is_vertical() || parsed.is_diagonal()) {
+            return Err(line.error(
+                line.text,
+                "line is neither horizontal, vertical nor diagonal",
+            ));
+        }
+        Ok(parsed)
+    }
+
+    /// All points on the line, for horizontal, vertical and 45° diagonal lines.
+    pub fn covering_points(&self) -> Vec<Point> {
+        if self.is_horizontal() {
+            self.normalized_y()
+                .map(|y| Point { x: self.from.x, y })
+                .collect()
+        } else if self.is_vertical() {
+            self.normalized_x()
+                .map(|x| Point { y: self.from.y, x })
+                .collect()
+        } else if self.is_diagonal() {
+            let x_diff: i16 = if self.from.x > self.to.x { -1 } else { 1 };
+            let y_diff: i16 = if self.from.y > self.to.y { -1 } else { 1 };
+
+            let mut result = Vec::new();
+            for i in 0i16..(self.normalized_x().len() as i16) {
+                result.push(Point {
+                    x: (self.from.x as i16 + i * x_diff) as u16,
+                    y: (self.from.y as i16 + i * y_diff) as u16,
+                });
+            }
+            result
+        } else {
+            panic!("unknown line construct: {:?}", self);
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.from.x == self.to.x
+    }
+    pub fn is_vertical(&self) -> bool {
+        self.from.y == self.to.y
+    }
+    pub fn is_diagonal(&self) -> bool {
+        self.normalized_x().len() == self.normalized_y().len()
+    }
+
+    fn normalized_x(&self) -> RangeInclusive<u16> {
+        if self.from.x < self.to.x {
+            self.from.x..=self.to.x
+        } else {
+            self.to.x..=self.from.x
+        }
+    }
+    fn normalized_y(&self) -> RangeInclusive<u16> {
+        if self.from.y < self.to.y {
+            self.from.y..=self.to.y
+        } else {
+            self.to.y..=self.from.y
+        }
+    }
+}
+
+/// Parses one `Line` per non-empty input line.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|line| Line::parse_at(&line))
+        .collect()
+}
+
+/// Number of points covered by at least two lines. Diagonal lines are
+/// skipped when `only_horizontal_and_vertical` is set.
+pub fn part_x<'a>(
+    lines: impl Iterator<Item = &'a Line>,
+    only_horizontal_and_vertical: bool,
+) -> usize {
+    let mut found = HashSet::new();
+
+    let mut dangerous = HashSet::new();
+
+    for line in lines {
+        if only_horizontal_and_vertical && !(line.is_horizontal() || line.is_vertical()) {
+            continue;
+        }
+        for point in line.covering_points() {
+            if found.contains(&point) {
+                dangerous.insert(point);
+            } else {
+                found.insert(point);
+            }
+        }
+    }
+
+    dangerous.len()
+}
+
+/// Day 5: Hydrothermal Venture
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part_1(lines: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part_x(lines.iter(), true))
+    }
+
+    fn part_2(lines: &Self::Input) -> Result<Self::Answer2> {
+        Ok(part_x(lines.iter(), false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use test_case::test_case;
+
+    const TEST_DATA: &str = "
+        0,9 -> 5,9
+        8,0 -> 0,8
+        9,4 -> 3,4
+        2,2 -> 2,1
+        7,0 -> 7,4
+        6,4 -> 2,0
+        0,9 -> 2,9
+        3,4 -> 1,4
+        0,0 -> 8,8
+        5,5 -> 8,2
+        ";
+
+    #[test]
+    fn test_part_1() {
+        let input = parse(TEST_DATA).unwrap();
+
+        assert_eq!(part_x(input.iter(), true), 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parse(TEST_DATA).unwrap();
+
+        assert_eq!(part_x(input.iter(), false), 12);
+    }
+
+    #[test_case(Line{from: Point{x: 1,y: 1}, to:Point{x: 1,y: 3}}, &[Point{x: 1,y: 1}, Point{x: 1,y: 2}, Point{x: 1,y: 3}])]
+    #[test_case(Line{from: Point{x: 9,y: 7}, to: Point{x: 7,y:7}}, &[Point{x: 9,y: 7}, Point{x: 8,y: 7}, Point{x: 7,y: 7}])]
+    #[test_case(Line{from: Point{x: 1,y: 1}, to: Point{x: 3,y:3}}, &[Point{x: 1,y: 1}, Point{x: 2,y: 2}, Point{x: 3,y: 3}])]
+    #[test_case(Line{from: Point{x: 9,y: 7}, to: Point{x: 7,y:9}}, &[Point{x: 9,y: 7}, Point{x: 8,y: 8}, Point{x: 7,y: 9}])]
+    fn point_covering(line: Line, expected: &[Point]) {
+        let covered: HashSet<Point> = HashSet::from_iter(line.covering_points().iter().cloned());
+        let wanted: HashSet<Point> = HashSet::from_iter(expected.iter().cloned());
+        assert_eq!(covered, wanted);
+    }
+
+    #[test_case(
+        "0,9 -> 5,9\n8,0 -> 0;8",
+        ParseError::new(2, 8, "0;8", "expected a point like `0,9`")
+    )]
+    #[test_case(
+        "0,9 -> 5,9\n\n8,0 <- 0,8",
+        ParseError::new(3, 1, "8,0 <- 0,8", "expected a line like `0,9 -> 5,9`")
+    )]
+    #[test_case(
+        "  0,9 -> 5,x",
+        ParseError::new(1, 12, "x", "invalid digit found in string")
+    )]
+    #[test_case(
+        "0,9 -> 5,7",
+        ParseError::new(
+            1,
+            1,
+            "0,9 -> 5,7",
+            "line is neither horizontal, vertical nor diagonal"
+        )
+    )]
+    fn parse_errors(input: &str, expected: ParseError) {
+        assert_eq!(parse(input).unwrap_err(), expected);
+    }
+
+    const GRID: u16 = 20;
+
+    /// Horizontal, vertical and diagonal lines within a `GRID` sized square.
+    fn line() -> impl Strategy<Value = Line> {
+        (0..GRID, 0..GRID, 0..GRID, 0..GRID, 0..3).prop_map(|(x1, y1, x2, y2, kind)| {
+            let to = match kind {
+                0 => Point { x: x1, y: y2 },
+                1 => Point { x: x2, y: y1 },
+                _ => {
+                    // as far towards (x2, y2) as a 45° line stays on the grid
+                    let len = (x2 as i16 - x1 as i16)
+                        .abs()
+                        .min((y2 as i16 - y1 as i16).abs());
+                    let dx = if x2 < x1 { -len } else { len };
+                    let dy = if y2 < y1 { -len } else { len };
+                    Point {
+                        x: (x1 as i16 + dx) as u16,
+                        y: (y1 as i16 + dy) as u16,
+                    }
+                }
+            };
+            Line {
+                from: Point { x: x1, y: y1 },
+                to,
+            }
+        })
+    }
+
+    /// Walks every line on a dense grid and counts the cells hit twice or more.
+    fn dense(lines: &[Line], only_horizontal_and_vertical: bool) -> usize {
+        let mut grid = [[0u8; GRID as usize]; GRID as usize];
+
+        for line in lines {
+            let dx = (line.to.x as i16 - line.from.x as i16).signum();
+            let dy = (line.to.y as i16 - line.from.y as i16).signum();
+            if only_horizontal_and_vertical && dx != 0 && dy != 0 {
+                continue;
+            }
+
+            let (mut x, mut y) = (line.from.x as i16, line.from.y as i16);
+            loop {
+                grid[x as usize][y as usize] += 1;
+                if (x, y) == (line.to.x as i16, line.to.y as i16) {
+                    break;
+                }
+                x += dx;
+                y += dy;
+            }
+        }
+
+        grid.iter().flatten().filter(|&&hits| hits >= 2).count()
+    }
+
+    proptest! {
+        #[test]
+        fn part_1_matches_dense_grid(lines in prop::collection::vec(line(), 0..30)) {
+            prop_assert_eq!(part_x(lines.iter(), true), dense(&lines, true));
+        }
+
+        #[test]
+        fn part_2_matches_dense_grid(lines in prop::collection::vec(line(), 0..30)) {
+            prop_assert_eq!(part_x(lines.iter(), false), dense(&lines, false));
+        }
+    }
+}
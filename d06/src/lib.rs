@@ -0,0 +1,66 @@
+use aoc_common::{Result, Solution};
+
+/// Day 6: Lanternfish
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u16>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(aoc_common::parse_separated(input, ',')?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(calc(input, 80))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(calc(input, 256))
+    }
+}
+
+/// Number of lanternfish after `days`, starting with the given timers.
+pub fn calc(input: &[u16], days: u16) -> usize {
+    // fish sharing a timer behave the same, so only count how many have each
+    // timer. Timers above 8 don't occur in spawned fish, but are valid input.
+    let max_timer = input.iter().copied().max().unwrap_or(0).max(8);
+    let mut timers = vec![0usize; usize::from(max_timer) + 1];
+    for &fish in input {
+        timers[usize::from(fish)] += 1;
+    }
+
+    for _ in 1..=days {
+        let spawning = timers[0];
+        timers.rotate_left(1);
+        timers[6] += spawning;
+        *timers.last_mut().unwrap() -= spawning;
+        timers[8] += spawning;
+    }
+
+    timers.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static NUMBERS: [u16; 5] = [3, 4, 3, 1, 2];
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(calc(&NUMBERS, 80), 5934);
+    }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(calc(&NUMBERS, 256), 26_984_457_539);
+    }
+
+    #[test]
+    fn test_timers_above_8() {
+        assert_eq!(calc(&[10], 10), 1);
+        assert_eq!(calc(&[10], 11), 2);
+        assert_eq!(calc(&[10, 3], 18), calc(&[3], 18) + 3);
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::{Result, Solution};
+
+/// Day 7: The Treachery of Whales
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(aoc_common::parse_separated(input, ',')?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(calc(input, cost_part_1))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(calc(input, cost_part_2))
+    }
+}
+
+/// Fuel for moving `distance` steps at one unit per step.
+pub fn cost_part_1(distance: i32) -> i32 {
+    distance
+}
+
+/// Fuel for moving `distance` steps when every step costs one more than the
+/// previous one.
+pub fn cost_part_2(distance: i32) -> i32 {
+    // too direct for real math,
+    // formula from:
+    // https://www.wolframalpha.com/input/?i=0%2C1%2C3%2C6%2C10%2C15%2C21%2C28%2C36%2C45%2C55%2C66%2C78%2C91%2C105%2C120%2C136%2C..
+    (0.5 * distance as f32 * (distance + 1) as f32) as i32
+}
+
+/// Least fuel needed to align all crabs on one position, with `cost_fn`
+/// giving the fuel for a distance.
+pub fn calc<F>(input: &[i32], cost_fn: F) -> i32
+where
+    F: Fn(i32) -> i32,
+{
+    let min = *input.iter().min().unwrap();
+    let max = *input.iter().max().unwrap();
+
+    (min..=max)
+        .map(|pos| {
+            input
+                .iter()
+                .map(|crab| cost_fn((crab - pos).abs()))
+                .sum::<i32>()
+        })
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    static TEST_DATA: [i32; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(calc(&TEST_DATA, cost_part_1), 37);
+    }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(calc(&TEST_DATA, cost_part_2), 168);
+    }
+
+    /// Least fuel over every position from 0 to past the furthest crab,
+    /// adding up the fuel step by step.
+    fn full_scan(input: &[i32], increasing: bool) -> i32 {
+        let max = *input.iter().max().unwrap();
+
+        (0..=max + 10)
+            .map(|pos| {
+                let mut fuel = 0;
+                for crab in input {
+                    for step in 1..=(crab - pos).abs() {
+                        fuel += if increasing { step } else { 1 };
+                    }
+                }
+                fuel
+            })
+            .min()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn part_1_matches_full_scan(input in prop::collection::vec(0..100i32, 1..30)) {
+            prop_assert_eq!(calc(&input, cost_part_1), full_scan(&input, false));
+        }
+
+        #[test]
+        fn part_2_matches_full_scan(input in prop::collection::vec(0..100i32, 1..30)) {
+            prop_assert_eq!(calc(&input, cost_part_2), full_scan(&input, true));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use aoc_common::{Error, InputLine, ParseError, Result, Solution};
+use std::str::FromStr;
+
+/// The lit segments of one seven-segment digit, like `cfbegad`. Only built
+/// by parsing, so it holds nothing but the segments `a` to `g`.
+#[derive(Clone, Debug)]
+pub struct Digit(String);
+
+impl Digit {
+    /// Parses `text`, a slice of `line`, which may only use the segments `a`
+    /// to `g`, each at most once.
+    pub fn parse_at(line: &InputLine, text: &str) -> Result<Self, ParseError> {
+        for (i, c) in text.char_indices() {
+            let segment = &text[i..i + c.len_utf8()];
+            if !('a'..='g').contains(&c) {
+                return Err(line.error(segment, "expected a segment from `a` to `g`"));
+            }
+            if text[..i].contains(c) {
+                return Err(line.error(segment, "segment is lit twice"));
+            }
+        }
+        Ok(Digit(text.to_string()))
+    }
+
+    /// The lit segments as a bitmask, `a` being the lowest bit.
+    pub fn segments(&self) -> u8 {
+        self.0.bytes().fold(0, |mask, b| mask | 1 << (b - b'a'))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Digit {
+    type Err = ParseError;
+
+    /// Parses a single digit on its own, reporting errors on line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Digit::parse_at(&InputLine::new(1, s), s)
+    }
+}
+
+/// One entry per display: the ten unique patterns and the four output digits.
+#[derive(Debug)]
+pub struct Input(pub Vec<(Vec<Digit>, Vec<Digit>)>);
+
+/// Day 8: Seven Segment Search
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        part_2(input).ok_or_else(|| Error::NoSolution("could not decode all displays".into()))
+    }
+}
+
+/// Parses one display per non-empty line, like `<10 patterns> | <4 digits>`.
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|line| {
+            let (lhs, rhs) = line
+                .text
+                .split_once('|')
+                .ok_or_else(|| line.error(line.text, "expected `|` between patterns and output"))?;
+            Ok((parse_digits(&line, lhs, 10)?, parse_digits(&line, rhs, 4)?))
+        })
+        .collect::<Result<_, _>>()
+        .map(Input)
+}
+
+fn parse_digits(line: &InputLine, text: &str, count: usize) -> Result<Vec<Digit>, ParseError> {
+    let digits = text
+        .split_whitespace()
+        .map(|digit| Digit::parse_at(line, digit))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() != count {
+        let message = format!("expected {} digits, found {}", count, digits.len());
+        return Err(line.error(text.trim(), message));
+    }
+    Ok(digits)
+}
+
+/// Number of output digits that are a 1, 4, 7 or 8.
+pub fn part_1(input: &Input) -> usize {
+    input
+        .0
+        .iter()
+        .flat_map(|l| l.1.clone())
+        .filter(|digit| matches!(digit.0.len(), 2 | 4 | 3 | 7))
+        .count()
+}
+
+/// Sum of all decoded output values, `None` if a display can't be decoded.
+pub fn part_2(input: &Input) -> Option<usize> {
+    input
+        .0
+        .iter()
+        .map(|(patterns, output)| decode(patterns, output))
+        .sum()
+}
+
+/// Decodes the output value of a single display.
+pub fn decode(patterns: &[Digit], output: &[Digit]) -> Option<usize> {
+    // the digits with a unique segment count are enough to tell the others
+    // apart, by how many segments they share with `1` and `4`.
+    let with_len = |len: u32| {
+        patterns
+            .iter()
+            .chain(output)
+            .map(Digit::segments)
+            .find(|s| s.count_ones() == len)
+    };
+    let one = with_len(2)?;
+    let four = with_len(4)?;
+
+    output.iter().try_fold(0, |number, digit| {
+        let segments = digit.segments();
+        let shared_one = (segments & one).count_ones();
+        let shared_four = (segments & four).count_ones();
+
+        let value = match (segments.count_ones(), shared_one, shared_four) {
+            (2, _, _) => 1,
+            (3, _, _) => 7,
+            (4, _, _) => 4,
+            (7, _, _) => 8,
+            (5, 2, _) => 3,
+            (5, _, 3) => 5,
+            (5, _, 2) => 2,
+            (6, _, 4) => 9,
+            (6, 2, _) => 0,
+            (6, 1, _) => 6,
+            _ => return None,
+        };
+        Some(number * 10 + value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &[&str] = &[
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
+        "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
+        "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
+        "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
+        "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
+        "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
+        "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
+        "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
+        "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
+        "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+    ];
+
+    #[test]
+    fn test_part_1() {
+        let input = parse_input(&INPUT.join("\n")).unwrap();
+
+        assert_eq!(part_1(&input), 26);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parse_input(&INPUT.join("\n")).unwrap();
+
+        assert_eq!(part_2(&input), Some(61229));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |input: &str| parse_input(input).unwrap_err();
+
+        assert_eq!(
+            err(&INPUT[0].replace('|', "/")).message,
+            "expected `|` between patterns and output"
+        );
+        assert_eq!(
+            err(&format!("{}\n{}", INPUT[0], INPUT[1].replace("cbg", "cbh"))),
+            ParseError::new(2, 16, "h", "expected a segment from `a` to `g`")
+        );
+        assert_eq!(
+            err("ab | ab"),
+            ParseError::new(1, 1, "ab", "expected 10 digits, found 1")
+        );
+    }
+
+    #[test]
+    fn test_digit_from_str() {
+        let digit: Digit = "cfbegad".parse().unwrap();
+        assert_eq!(digit.as_str(), "cfbegad");
+        assert_eq!(digit.segments(), 0b111_1111);
+        assert_eq!("ac".parse::<Digit>().unwrap().segments(), 0b101);
+
+        assert_eq!(
+            "abz".parse::<Digit>().unwrap_err(),
+            ParseError::new(1, 3, "z", "expected a segment from `a` to `g`")
+        );
+        assert_eq!(
+            "aba".parse::<Digit>().unwrap_err().message,
+            "segment is lit twice"
+        );
+        assert!("A".parse::<Digit>().is_err());
+    }
+}